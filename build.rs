@@ -1,12 +1,15 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+#[cfg(feature = "network")]
 use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+#[cfg(feature = "network")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 // Chain definitions from chainid.network
 pub type Root = Vec<ChainInfo>;
@@ -36,6 +39,9 @@ pub struct ChainInfo {
     #[serde(default)]
     pub red_flags: Vec<String>,
     pub parent: Option<Parent>,
+    /// Overlay-only extension: block time override in milliseconds
+    #[serde(default)]
+    pub block_time_ms: Option<u64>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -83,6 +89,15 @@ pub struct Bridge {
     pub url: String,
 }
 
+/// Curated chain sets selectable by name in `CHAINLIST_INCLUDE`
+const MAINNET_CHAIN_IDS: &[u64] = &[1, 25, 56, 100, 137, 250, 1284, 43114];
+const MAJOR_L2_CHAIN_IDS: &[u64] = &[
+    10, 324, 1101, 5000, 8453, 42161, 42170, 42220, 59144, 81457, 534352,
+];
+const TESTNET_CHAIN_IDS: &[u64] = &[
+    300, 17000, 59141, 80002, 84532, 421614, 534351, 560048, 11155111, 11155420,
+];
+
 /// Simplified chain data for code generation
 #[derive(Debug, Clone)]
 struct ChainData {
@@ -101,17 +116,64 @@ struct ChainData {
     native_currency_decimals: u8,
     slip44: Option<i64>,
     block_time_ms: u64,
+    status: Option<String>,
+    red_flags: Vec<String>,
+    network_id: u64,
+    title: Option<String>,
+    chain: String,
+    ens: Option<Ens>,
+    parent: Option<Parent>,
 }
 
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-env-changed=CHAINS_JSON_PATH");
     println!("cargo:rerun-if-env-changed=CHAINS_JSON_URL");
-
-    let chains_json = load_chains_json();
+    println!("cargo:rerun-if-env-changed=CHAINLIST_INCLUDE");
+    println!("cargo:rerun-if-env-changed=CHAINS_JSON_SHA256");
+    println!("cargo:rerun-if-env-changed=CHAINS_OVERLAY_PATH");
+    println!("cargo:rerun-if-env-changed=CHAINLIST_UPDATE_LOCK");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    println!("cargo:rustc-check-cfg=cfg(chainlist_selected)");
+
+    let loaded = load_chains_json();
+    let chains_sha256 = sha256_hex(&loaded.text);
+    let upstream = parse_chains(&loaded.text);
+
+    // Merge private/internal chains on top of the upstream list
+    let (chains_json, overlays) = apply_overlays(loaded.text);
+
+    // Resolve variant names over the full list so they don't depend on the selection
+    let upstream_ids = upstream
+        .iter()
+        .map(|chain| chain.chain_id as u64)
+        .collect::<HashSet<_>>();
+    let overlay_only = parse_chains(&chains_json)
+        .into_iter()
+        .filter(|chain| !upstream_ids.contains(&(chain.chain_id as u64)))
+        .collect::<Vec<_>>();
+    let variant_names = resolve_variant_names(&upstream, &overlay_only);
+
+    // Keep only the selected chains, if a selection was requested
+    let chains_json = match selected_chain_ids() {
+        Some(mut selection) => {
+            // Chains only an overlay defines are always kept; overridden
+            // upstream chains follow the selection like any other
+            selection.extend(overlay_only.iter().map(|chain| chain.chain_id as u64));
+            select_chains(&chains_json, &selection)
+        }
+        None => chains_json,
+    };
 
     // Generate the code
-    let chain_code = generate_chain_code(&chains_json);
+    let provenance = Provenance {
+        source: &loaded.source,
+        kind: &loaded.kind,
+        retrieved_at: loaded.retrieved_at,
+        sha256: &chains_sha256,
+        overlays: &overlays,
+    };
+    let chain_code = generate_chain_code(&chains_json, &variant_names, &provenance);
 
     // Format the generated code
     let formatted_code = format_rust_code(&chain_code);
@@ -130,11 +192,79 @@ fn main() {
     println!("cargo:info=Generated Chain enum from chainid.network/chains.json");
 }
 
-fn load_chains_json() -> String {
+/// Where the chains.json used for code generation came from
+#[cfg_attr(not(feature = "network"), allow(dead_code))]
+enum SourceKind {
+    /// Supplied through `CHAINS_JSON_PATH`
+    Override,
+    /// Bundled snapshot read without a download attempt (the default)
+    Snapshot,
+    /// Previous download (kept in `OUT_DIR`) reused within the TTL, or after a failed download
+    Cache,
+    /// Freshly downloaded
+    Download,
+}
+
+/// Raw chains.json plus where and when it was obtained
+struct LoadedChains {
+    text: String,
+    source: String,
+    kind: SourceKind,
+    /// Unix timestamp (seconds) of the download. Local files only carry one
+    /// when `SOURCE_DATE_EPOCH` is set, since their mtime is the checkout time.
+    retrieved_at: Option<u64>,
+}
+
+impl LoadedChains {
+    fn from_file(path: &Path, kind: SourceKind, context: &str) -> Self {
+        let text = fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("Failed to read {context} {:?}: {e}", path));
+        let source = display_path(path);
+        Self {
+            text: verify_sha256(text, &source, path),
+            source,
+            kind,
+            retrieved_at: source_date_epoch(),
+        }
+    }
+}
+
+/// Path as recorded in the generated code: relative to the crate root when
+/// inside it, so binaries don't depend on where the crate was built.
+fn display_path(path: &Path) -> String {
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+    let absolute = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    manifest_dir
+        .and_then(|dir| {
+            let dir = fs::canonicalize(&dir).unwrap_or(dir);
+            absolute
+                .strip_prefix(dir)
+                .ok()
+                .map(|relative| relative.display().to_string())
+        })
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+/// Reproducible-build timestamp, see <https://reproducible-builds.org/specs/source-date-epoch/>
+fn source_date_epoch() -> Option<u64> {
+    env::var("SOURCE_DATE_EPOCH").ok()?.trim().parse().ok()
+}
+
+#[cfg(feature = "network")]
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+fn load_chains_json() -> LoadedChains {
     // Prefer env override for reproducibility in CI or vendored builds
     if let Ok(path) = env::var("CHAINS_JSON_PATH") {
-        println!("cargo:rustc-env=CHAINS_JSON_PATH={path}");
-        return fs::read_to_string(path).expect("Failed to read CHAINS_JSON_PATH file");
+        return LoadedChains::from_file(
+            Path::new(&path),
+            SourceKind::Override,
+            "CHAINS_JSON_PATH file",
+        );
     }
 
     let manifest_dir =
@@ -143,65 +273,107 @@ fn load_chains_json() -> String {
     let local = cache_dir.join("chains.json");
     println!("cargo:rerun-if-changed={}", local.display());
 
-    // In docs.rs or offline builds, use local file without TTL check
+    // Downloads are opt-in through the `network` feature; `offline` and docs.rs always win
     let is_docs_rs = env::var("DOCS_RS").is_ok();
     let is_offline = env::var("CARGO_FEATURE_OFFLINE").is_ok();
+    let network = cfg!(feature = "network") && !is_docs_rs && !is_offline;
 
-    if is_docs_rs || is_offline {
-        if local.exists() {
-            return fs::read_to_string(&local)
-                .expect("Failed to read local chains.json in offline mode");
-        } else {
+    if !network {
+        if env::var("CHAINS_JSON_URL").is_ok() {
             panic!(
-                "chains.json not found at {:?} and network access is disabled",
-                local
+                "CHAINS_JSON_URL is set but downloads are disabled. Enable the `network` feature \
+                 (without `offline`) to download chains.json, or set CHAINS_JSON_PATH to a local file."
             );
         }
+        return load_snapshot(&local);
     }
 
-    let ttl = Duration::from_secs(2 * 60 * 60); // 2h
+    download_or_cache(&local)
+}
 
-    if !is_stale(&local, ttl) {
-        if let Ok(text) = fs::read_to_string(&local) {
-            return text;
-        }
+/// Reads the bundled snapshot without touching the network.
+fn load_snapshot(local: &Path) -> LoadedChains {
+    if !local.exists() {
+        panic!(
+            "chains.json snapshot not found at {:?}.\n\
+             Download https://chainid.network/chains.json to that path, enable the \
+             `network` feature, or point CHAINS_JSON_PATH at a local copy.",
+            local
+        );
     }
 
+    LoadedChains::from_file(local, SourceKind::Snapshot, "chains.json snapshot")
+}
+
+#[cfg(not(feature = "network"))]
+fn download_or_cache(_snapshot: &Path) -> LoadedChains {
+    unreachable!("downloads require the `network` feature")
+}
+
+/// Reuses the last download within the TTL, otherwise downloads a fresh
+/// chains.json. `CHAINS_JSON_REFRESH=1` skips the TTL.
+///
+/// Downloads are cached in `OUT_DIR`, never over the committed snapshot, which
+/// is only used when the download fails and nothing was cached yet.
+#[cfg(feature = "network")]
+fn download_or_cache(snapshot: &Path) -> LoadedChains {
+    println!("cargo:rerun-if-env-changed=CHAINS_JSON_REFRESH");
+    let ttl = Duration::from_secs(2 * 60 * 60); // 2h
+    let refresh = env::var("CHAINS_JSON_REFRESH").is_ok_and(|v| v == "1");
+    let cache = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set by Cargo"))
+        .join("chains.download.json");
     let url = env::var("CHAINS_JSON_URL")
         .unwrap_or_else(|_| "https://chainid.network/chains.json".to_string());
 
-    // Try to download, fallback to local file if download fails
+    if !refresh && !is_stale(&cache, ttl) {
+        return load_cached_download(&cache, snapshot, url);
+    }
+
     match download_chains_json(&url) {
         Some(text) => {
-            if let Some(parent) = local.parent() {
-                if let Err(e) = fs::create_dir_all(parent) {
-                    panic!("Failed to create chains.json directory {:?}: {e}", parent);
-                }
-            }
-            fs::write(&local, &text).unwrap_or_else(|e| {
-                panic!("Failed to write downloaded chains.json to {:?}: {e}", local)
+            // Verify before caching so a tampered download never lands on disk
+            let text = verify_sha256(text, &url, snapshot);
+            fs::write(&cache, &text).unwrap_or_else(|e| {
+                panic!("Failed to write downloaded chains.json to {:?}: {e}", cache)
             });
-            text
+            LoadedChains {
+                text,
+                source: url,
+                kind: SourceKind::Download,
+                retrieved_at: Some(unix_seconds(SystemTime::now())),
+            }
+        }
+        None if cache.exists() => {
+            println!("cargo:warning=Network download failed, using the previous download of {url}");
+            load_cached_download(&cache, snapshot, url)
         }
         None => {
-            // Fallback to local file
-            if local.exists() {
-                println!(
-                    "cargo:warning=Network download failed, using local chains.json at {:?}",
-                    local
-                );
-                fs::read_to_string(&local)
-                    .expect("Failed to read local chains.json after network failure")
-            } else {
-                panic!(
-                    "Failed to download chains.json from {} and no local file exists at {:?}",
-                    url, local
-                );
-            }
+            println!(
+                "cargo:warning=Network download failed, using the bundled chains.json snapshot"
+            );
+            load_snapshot(snapshot)
         }
     }
 }
 
+/// Reads a previous download from `OUT_DIR`, verified against the same pin as fresh downloads.
+#[cfg(feature = "network")]
+fn load_cached_download(cache: &Path, snapshot: &Path, url: String) -> LoadedChains {
+    let text = fs::read_to_string(cache)
+        .unwrap_or_else(|e| panic!("Failed to read cached chains.json {:?}: {e}", cache));
+    let retrieved_at = fs::metadata(cache)
+        .and_then(|meta| meta.modified())
+        .map(unix_seconds)
+        .ok();
+    LoadedChains {
+        text: verify_sha256(text, &url, snapshot),
+        source: url,
+        kind: SourceKind::Cache,
+        retrieved_at,
+    }
+}
+
+#[cfg(feature = "network")]
 fn download_chains_json(url: &str) -> Option<String> {
     let client = match Client::builder().timeout(Duration::from_secs(30)).build() {
         Ok(c) => c,
@@ -240,6 +412,183 @@ fn download_chains_json(url: &str) -> Option<String> {
     }
 }
 
+/// Merges the JSON files listed in `CHAINS_OVERLAY_PATH` (separated like `PATH`)
+/// into the chain list, in order.
+///
+/// Each file holds an array of chain records in the chains.json schema. An entry
+/// whose `chainId` already exists overrides only the fields it sets; other
+/// entries are added as new chains and must be complete records. Overlay entries
+/// may also set `blockTimeMs`. Returns the merged JSON and the overlay file paths.
+fn apply_overlays(json_str: String) -> (String, Vec<String>) {
+    let Some(paths) = env::var_os("CHAINS_OVERLAY_PATH") else {
+        return (json_str, Vec::new());
+    };
+
+    let mut chains: Vec<serde_json::Value> = match serde_json::from_str(&json_str) {
+        Ok(chains) => chains,
+        Err(err) => panic!("Failed to parse chains.json: {err}"),
+    };
+    let mut overlay_paths = Vec::new();
+
+    for path in env::split_paths(&paths).filter(|p| !p.as_os_str().is_empty()) {
+        println!("cargo:rerun-if-changed={}", path.display());
+        overlay_paths.push(display_path(&path));
+        let text = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Failed to read chain overlay {:?}: {e}", path));
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&text)
+            .unwrap_or_else(|e| panic!("Failed to parse chain overlay {:?}: {e}", path));
+
+        for entry in entries {
+            let Some(id) = entry.get("chainId").and_then(|id| id.as_u64()) else {
+                panic!(
+                    "Chain overlay {:?} has an entry without a numeric chainId",
+                    path
+                );
+            };
+            let Some(fields) = entry.as_object() else {
+                panic!(
+                    "Chain overlay {:?}: entry for chain {id} is not an object",
+                    path
+                );
+            };
+
+            let existing = chains
+                .iter_mut()
+                .find(|chain| chain.get("chainId").and_then(|id| id.as_u64()) == Some(id));
+            match existing.and_then(|chain| chain.as_object_mut()) {
+                Some(chain) => {
+                    for (key, value) in fields {
+                        chain.insert(key.clone(), value.clone());
+                    }
+                }
+                None => {
+                    if let Err(e) = serde_json::from_value::<ChainInfo>(entry.clone()) {
+                        panic!(
+                            "Chain overlay {:?}: new chain {id} is not a complete chain record: {e}",
+                            path
+                        );
+                    }
+                    chains.push(entry);
+                }
+            }
+        }
+    }
+
+    let merged = serde_json::to_string(&chains).expect("Failed to serialize merged chains");
+    (merged, overlay_paths)
+}
+
+/// Collects the chain ids and curated set names listed in `CHAINLIST_INCLUDE`.
+/// Returns `None` when no selection was made (all chains).
+///
+/// This is deliberately not a Cargo feature: features are unified across the
+/// dependency graph, so one crate narrowing the set would remove variants
+/// another crate relies on. The final build chooses the selection instead.
+fn selected_chain_ids() -> Option<BTreeSet<u64>> {
+    let include = env::var("CHAINLIST_INCLUDE").ok()?;
+    let mut selection = BTreeSet::new();
+    for item in include.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        match item {
+            "mainnets" => selection.extend(MAINNET_CHAIN_IDS),
+            "major-l2s" => selection.extend(MAJOR_L2_CHAIN_IDS),
+            "testnets" => selection.extend(TESTNET_CHAIN_IDS),
+            _ => {
+                let id = item.parse::<u64>().unwrap_or_else(|e| {
+                    panic!(
+                        "Invalid entry {item:?} in CHAINLIST_INCLUDE: {e} \
+                         (expected a chain id, `mainnets`, `major-l2s` or `testnets`)"
+                    )
+                });
+                selection.insert(id);
+            }
+        }
+    }
+
+    println!("cargo:rustc-cfg=chainlist_selected");
+    Some(selection)
+}
+
+/// Filters the raw chains.json down to the selected chain ids, keeping every
+/// other field of the selected records untouched.
+fn select_chains(json_str: &str, selection: &BTreeSet<u64>) -> String {
+    let chains: Vec<serde_json::Value> = match serde_json::from_str(json_str) {
+        Ok(chains) => chains,
+        Err(err) => panic!("Failed to parse chains.json: {err}"),
+    };
+
+    let selected: Vec<serde_json::Value> = chains
+        .into_iter()
+        .filter(|chain| {
+            chain
+                .get("chainId")
+                .and_then(|id| id.as_u64())
+                .is_some_and(|id| selection.contains(&id))
+        })
+        .collect();
+
+    let found: BTreeSet<u64> = selected
+        .iter()
+        .filter_map(|chain| chain.get("chainId").and_then(|id| id.as_u64()))
+        .collect();
+    for missing in selection.difference(&found) {
+        println!("cargo:warning=Selected chain id {missing} not found in chains.json");
+    }
+
+    serde_json::to_string(&selected).expect("Failed to serialize selected chains")
+}
+
+fn sha256_hex(text: &str) -> String {
+    Sha256::digest(text.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Returns the pinned SHA-256 for the chains.json at `json_path` together with
+/// where the pin came from: `CHAINS_JSON_SHA256`, or a sibling
+/// `<json_path>.sha256` file (`sha256sum` output format is accepted).
+fn pinned_sha256(json_path: &Path) -> Option<(String, String)> {
+    if let Ok(digest) = env::var("CHAINS_JSON_SHA256") {
+        return Some((
+            digest.trim().to_lowercase(),
+            "CHAINS_JSON_SHA256".to_string(),
+        ));
+    }
+
+    let mut pin_path = json_path.as_os_str().to_owned();
+    pin_path.push(".sha256");
+    let pin_path = PathBuf::from(pin_path);
+    println!("cargo:rerun-if-changed={}", pin_path.display());
+
+    let text = fs::read_to_string(&pin_path).ok()?;
+    let digest = text.split_whitespace().next().unwrap_or_default();
+    Some((digest.to_lowercase(), pin_path.display().to_string()))
+}
+
+/// Fails the build if `text` (loaded from `origin`) does not match the pinned digest.
+fn verify_sha256(text: String, origin: &str, json_path: &Path) -> String {
+    let Some((expected, pin_origin)) = pinned_sha256(json_path) else {
+        return text;
+    };
+
+    if expected.len() != 64 || !expected.chars().all(|c| c.is_ascii_hexdigit()) {
+        panic!("Invalid SHA-256 pin {expected:?} from {pin_origin}: expected 64 hex characters");
+    }
+
+    let actual = sha256_hex(&text);
+    if actual != expected {
+        panic!(
+            "chains.json from {origin} does not match the pinned checksum\n  \
+             expected: {expected} (from {pin_origin})\n  \
+             actual:   {actual}\n\
+             Refusing to build with unverified chain data; update the pin if the change is expected."
+        );
+    }
+
+    text
+}
+
+#[cfg(feature = "network")]
 fn is_stale(path: &Path, ttl: Duration) -> bool {
     match fs::metadata(path) {
         Ok(meta) => match meta.modified() {
@@ -265,14 +614,159 @@ fn chains_json_dir(manifest_dir: &Path) -> PathBuf {
     }
 }
 
-fn generate_chain_code(json_str: &str) -> String {
-    let chains: Root = match serde_json::from_str(json_str) {
+fn parse_chains(json_str: &str) -> Root {
+    match serde_json::from_str(json_str) {
         Ok(chains) => chains,
         Err(err) => panic!("Failed to parse chains.json: {err}"),
+    }
+}
+
+/// Maps every chain id to its enum variant name, backed by the committed
+/// `chains.lock` next to `chains.json`.
+///
+/// Names already in the lockfile are kept even if upstream renames the
+/// chain's shortName, so variants never change once published. Upstream
+/// chains missing from the lockfile get a freshly derived name, then overlay
+/// chains do. New names only live in memory and are reported as a cargo
+/// warning; `CHAINLIST_UPDATE_LOCK=1` writes the upstream ones to the lockfile.
+/// Overlay-only chains are never written to it.
+///
+/// Names must be unique identifiers. When two chains would share a name, the
+/// one that was locked first (or has the lower chain id) keeps it and the other
+/// gets its chain id appended; every collision is reported as a cargo warning.
+fn resolve_variant_names(
+    upstream: &[ChainInfo],
+    overlay_only: &[ChainInfo],
+) -> BTreeMap<u64, String> {
+    // Default chain names (used for known chains to ensure consistent naming)
+    let default_names = HashMap::from([
+        (1, "Mainnet"),
+        (56, "Bnb"),
+        (100, "Gnosis"),
+        (11155111, "Sepolia"),
+        (8453, "Base"),
+        (31337, "Hardhat"),
+    ]);
+
+    let manifest_dir =
+        PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set by Cargo"));
+    let lock_path = chains_json_dir(&manifest_dir).join("chains.lock");
+    println!("cargo:rerun-if-changed={}", lock_path.display());
+
+    let locked: BTreeMap<u64, String> = match fs::read_to_string(&lock_path) {
+        Ok(text) => serde_json::from_str(&text)
+            .unwrap_or_else(|e| panic!("Failed to parse name lockfile {:?}: {e}", lock_path)),
+        Err(_) => BTreeMap::new(),
+    };
+
+    let mut names = BTreeMap::new();
+    let mut taken = HashSet::new();
+    let mut collisions = Vec::new();
+
+    // Locked names win over new chains; they are only renamed if the lockfile itself is broken
+    for (&id, name) in &locked {
+        let name = claim_variant_name(name, id, &mut taken, &mut collisions);
+        names.insert(id, name);
+    }
+
+    // Upstream chains first, so overlays can't take names from them
+    let mut added = Vec::new();
+    for chains in [upstream, overlay_only] {
+        let mut new_chains = chains
+            .iter()
+            .filter(|chain| !names.contains_key(&(chain.chain_id as u64)))
+            .collect::<Vec<_>>();
+        new_chains.sort_by_key(|chain| chain.chain_id);
+
+        for chain in new_chains {
+            let id = chain.chain_id as u64;
+            // Get default enum variant name if it's a known chain, otherwise generate one
+            let name = default_names
+                .get(&chain.chain_id)
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| sanitize_enum_name(&chain.short_name, chain.chain_id));
+            let name = claim_variant_name(&name, id, &mut taken, &mut collisions);
+            names.insert(id, name);
+            added.push(id);
+        }
+    }
+
+    if !collisions.is_empty() {
+        println!(
+            "cargo:warning=Resolved {} colliding chain variant name(s): {}",
+            collisions.len(),
+            collisions.join("; ")
+        );
+    }
+
+    let overlay_ids = overlay_only
+        .iter()
+        .map(|chain| chain.chain_id as u64)
+        .collect::<HashSet<_>>();
+    let unlocked = added.iter().filter(|id| !overlay_ids.contains(id)).count();
+
+    // The source tree may be a shared registry copy: only write it when a
+    // maintainer asks for it, and never on docs.rs
+    let update_lock =
+        env::var("CHAINLIST_UPDATE_LOCK").is_ok_and(|v| v == "1") && env::var("DOCS_RS").is_err();
+    if unlocked > 0 && update_lock {
+        let lockable = names
+            .iter()
+            .filter(|(id, _)| !overlay_ids.contains(id))
+            .collect::<BTreeMap<_, _>>();
+        let text =
+            serde_json::to_string_pretty(&lockable).expect("Failed to serialize name lockfile");
+        match fs::write(&lock_path, text + "\n") {
+            Ok(()) => println!(
+                "cargo:warning=Updated {:?} ({unlocked} new chain name(s)); commit it to keep variant names stable",
+                lock_path
+            ),
+            Err(e) => println!("cargo:warning=Failed to update name lockfile {:?}: {e}", lock_path),
+        }
+    } else if unlocked > 0 {
+        println!(
+            "cargo:warning={unlocked} chain(s) missing from {:?} got unpinned variant names; \
+             run with CHAINLIST_UPDATE_LOCK=1 to record them",
+            lock_path
+        );
+    }
+
+    names
+}
+
+/// Provenance details baked into the generated `DATA_PROVENANCE`
+struct Provenance<'a> {
+    source: &'a str,
+    kind: &'a SourceKind,
+    retrieved_at: Option<u64>,
+    sha256: &'a str,
+    overlays: &'a [String],
+}
+
+fn generate_chain_code(
+    json_str: &str,
+    variant_names: &BTreeMap<u64, String>,
+    provenance: &Provenance,
+) -> String {
+    let chains = parse_chains(json_str);
+
+    let chains_sha256 = provenance.sha256;
+    let source = provenance.source;
+    let source_kind = match provenance.kind {
+        SourceKind::Override => quote! { DataSourceKind::Override },
+        SourceKind::Snapshot => quote! { DataSourceKind::Snapshot },
+        SourceKind::Cache => quote! { DataSourceKind::Cache },
+        SourceKind::Download => quote! { DataSourceKind::Download },
+    };
+    let retrieved_at = match provenance.retrieved_at {
+        Some(secs) => quote! { Some(#secs) },
+        None => quote! { None },
     };
+    let record_count = chains.len();
+    let overlays = provenance.overlays;
 
     // Process chain data
-    let mut chain_data = get_chains(&chains);
+    let mut chain_data = get_chains(&chains, variant_names);
     chain_data.sort_by_key(|c| c.id);
 
     // Generate enum variants
@@ -282,87 +776,76 @@ fn generate_chain_code(json_str: &str) -> String {
     for chain in &chain_data {
         let name_ident = format_ident!("{}", chain.name);
         let doc_comment = format!("{} (Chain ID: {})", chain.name_str, chain.id);
+        let id = chain.id;
         let variant = quote! {
             #[doc = #doc_comment]
-            #name_ident,
+            #name_ident = #id,
         };
         enum_variants.extend(variant);
     }
 
-    // Generate chain info entries for the match statement
+    // Generate the static chain info table, one entry per enum variant in the same order
     let chain_info_entries = chain_data
         .iter()
         .map(|chain| {
-            let name_ident = format_ident!("{}", chain.name);
             let id = chain.id;
             let name_str = &chain.name_str;
             let short_name = &chain.short_name;
             let info_url = &chain.info_url;
-            let features = if chain.features.is_empty() {
-                quote! { vec![] }
-            } else {
-                let feature_items = chain.features.iter().collect::<Vec<_>>();
-                quote! { vec![#(#feature_items.to_string()),*] }
-            };
+            let features = &chain.features;
             let currency_name = &chain.native_currency_name;
             let currency_symbol = &chain.native_currency_symbol;
             let decimals = chain.native_currency_decimals;
             let block_time = chain.block_time_ms;
-            let icon = if let Some(icon) = &chain.icon {
-                quote! { Some(#icon.to_string()) }
-            } else {
-                quote! { None }
-            };
-
-            let explorers = if chain.explorers.is_empty() {
-                quote! { vec![] }
-            } else {
-                let explorer_items = chain
-                    .explorers
-                    .iter()
-                    .map(|e| {
-                        let name = e.name.clone();
-                        let url = e.url.clone();
-                        let standard = e.standard.clone();
-                        let icon = if let Some(icon) = &e.icon {
-                            quote! { Some(#icon.to_string()) }
-                        } else {
-                            quote! { None }
-                        };
-                        quote! {
-                            Explorer {
-                                name: #name.to_string(),
-                                url: #url.to_string(),
-                                standard: #standard.to_string(),
-                                icon: #icon,
-                            }
+            let icon = optional_str(chain.icon.as_deref());
+
+            let explorers = chain
+                .explorers
+                .iter()
+                .map(|e| {
+                    let name = &e.name;
+                    let url = &e.url;
+                    let standard = &e.standard;
+                    let icon = optional_str(e.icon.as_deref());
+                    quote! {
+                        ExplorerInfo {
+                            name: #name,
+                            url: #url,
+                            standard: #standard,
+                            icon: #icon,
                         }
-                    })
-                    .collect::<Vec<_>>();
-                quote! { vec![#(#explorer_items),*] }
-            };
-
-            // Generate RPC URLs
-            let rpc_urls = if chain.rpc_urls.is_empty() {
-                quote! { vec![] }
-            } else {
-                let urls: Vec<_> = chain.rpc_urls.iter().collect();
-                quote! { vec![#(#urls),*] }
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let rpc_urls = &chain.rpc_urls;
+            let faucets = &chain.faucets;
+            let status = optional_str(chain.status.as_deref());
+            let red_flags = &chain.red_flags;
+            let network_id = chain.network_id;
+            let title = optional_str(chain.title.as_deref());
+            let chain_family = &chain.chain;
+            let ens = match &chain.ens {
+                Some(ens) => {
+                    let registry = &ens.registry;
+                    quote! { Some(EnsInfo { registry: #registry }) }
+                }
+                None => quote! { None },
             };
-
-            // Generate faucets
-            let faucets = if chain.faucets.is_empty() {
-                quote! { vec![] }
-            } else {
-                let faucet_items = chain
-                    .faucets
-                    .iter()
-                    .map(|url| {
-                        let url_str = url.clone();
-                        quote! { #url_str.to_string() }
-                    })
-                    .collect::<Vec<_>>();
-                quote! { vec![#(#faucet_items),*] }
+            let parent = match &chain.parent {
+                Some(parent) => {
+                    let type_field = &parent.type_field;
+                    let parent_chain = &parent.chain;
+                    let bridges = parent.bridges.iter().map(|b| &b.url);
+                    quote! {
+                        Some(ParentInfo {
+                            type_field: #type_field,
+                            chain: #parent_chain,
+                            bridges: &[#(#bridges),*],
+                        })
+                    }
+                }
+                None => quote! { None },
             };
 
             // Generate slip44
@@ -373,94 +856,261 @@ fn generate_chain_code(json_str: &str) -> String {
             };
 
             quote! {
-                Self::#name_ident => ChainInfo {
+                ChainInfo {
                     id: #id,
                     name: #name_str,
                     short_name: #short_name,
-                    rpc_urls: #rpc_urls,
-                    features: #features,
-                    faucets: #faucets,
-                    native_currency: NativeCurrency {
-                        name: #currency_name.to_string(),
-                        symbol: #currency_symbol.to_string(),
+                    rpc_urls: &[#(#rpc_urls),*],
+                    features: &[#(#features),*],
+                    faucets: &[#(#faucets),*],
+                    native_currency: NativeCurrencyInfo {
+                        name: #currency_name,
+                        symbol: #currency_symbol,
                         decimals: #decimals,
                     },
                     info_url: #info_url,
                     slip44: #slip44,
                     block_time_ms: #block_time,
                     icon: #icon,
-                    explorers: #explorers,
+                    explorers: &[#(#explorers),*],
+                    status: #status,
+                    red_flags: &[#(#red_flags),*],
+                    network_id: #network_id,
+                    title: #title,
+                    chain: #chain_family,
+                    ens: #ens,
+                    parent: #parent,
                 }
             }
         })
         .collect::<Vec<_>>();
+    let chain_count = chain_info_entries.len();
 
-    // Generate TryFrom match arms for the try_from implementation
-    let try_from_arms = chain_data
+    // Sorted chain ids and the matching variants, used for binary-search lookups
+    let chain_ids = chain_data.iter().map(|chain| chain.id).collect::<Vec<_>>();
+    let chain_variants = chain_data
         .iter()
         .map(|chain| {
             let name_ident = format_ident!("{}", chain.name);
-            let id = chain.id;
-
-            quote! {
-                #id => Ok(Self::#name_ident),
-            }
+            quote! { Chain::#name_ident }
         })
         .collect::<Vec<_>>();
+    // Direct variant -> table position arms, so `info()` needs no search
+    let chain_indices = chain_variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| quote! { #variant => #index, })
+        .collect::<TokenStream>();
 
     // Combine all the parts using quote!
     let generated_code = quote! {
-        use crate::schema::{ChainRecord, Explorer, NativeCurrency};
+        use crate::parse::{ParseRoute, UnsupportedInput};
+        use crate::provenance::DataSourceKind;
+        use crate::schema::{Bridge, ChainRecord, Ens, Explorer, NativeCurrency, Parent};
         use once_cell::sync::OnceCell;
+        use std::collections::HashMap;
         use strum_macros::EnumIter;
 
         #[doc = r" Chain metadata derived from chainid.network"]
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, Copy)]
         pub struct ChainInfo {
             pub id: u64,
             pub name: &'static str,
             pub short_name: &'static str,
-            pub rpc_urls: Vec<&'static str>,
-            pub features: Vec<String>,
-            pub faucets: Vec<String>,
-            pub native_currency: NativeCurrency,
+            pub rpc_urls: &'static [&'static str],
+            pub features: &'static [&'static str],
+            pub faucets: &'static [&'static str],
+            pub native_currency: NativeCurrencyInfo,
             pub info_url: &'static str,
             pub slip44: Option<i64>,
             pub block_time_ms: u64,
-            pub icon: Option<String>,
-            pub explorers: Vec<Explorer>,
+            pub icon: Option<&'static str>,
+            pub explorers: &'static [ExplorerInfo],
+            pub status: Option<&'static str>,
+            pub red_flags: &'static [&'static str],
+            pub network_id: u64,
+            pub title: Option<&'static str>,
+            /// Chain family symbol (the `chain` field), e.g. "ETH"
+            pub chain: &'static str,
+            pub ens: Option<EnsInfo>,
+            pub parent: Option<ParentInfo>,
+        }
+
+        #[doc = r" ENS registry entry stored in the static chain table."]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct EnsInfo {
+            pub registry: &'static str,
+        }
+
+        #[doc = r" Parent network entry stored in the static chain table."]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct ParentInfo {
+            /// Relationship to the parent, e.g. "L2" or "shard"
+            pub type_field: &'static str,
+            /// Parent chain reference, e.g. "eip155-1"
+            pub chain: &'static str,
+            /// Bridge URLs
+            pub bridges: &'static [&'static str],
+        }
+
+        impl ParentInfo {
+            /// Parent chain ID, if the reference has the `eip155-<id>` form.
+            pub fn chain_id(&self) -> Option<u64> {
+                self.chain.strip_prefix("eip155-")?.parse().ok()
+            }
+        }
+
+        impl From<&EnsInfo> for Ens {
+            fn from(info: &EnsInfo) -> Self {
+                Self {
+                    registry: info.registry.to_string(),
+                }
+            }
+        }
+
+        impl From<&ParentInfo> for Parent {
+            fn from(info: &ParentInfo) -> Self {
+                Self {
+                    type_field: info.type_field.to_string(),
+                    chain: info.chain.to_string(),
+                    bridges: info
+                        .bridges
+                        .iter()
+                        .map(|url| Bridge { url: url.to_string() })
+                        .collect(),
+                }
+            }
+        }
+
+        #[doc = r" Native currency details stored in the static chain table."]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct NativeCurrencyInfo {
+            pub name: &'static str,
+            pub symbol: &'static str,
+            pub decimals: u8,
+        }
+
+        #[doc = r" Block explorer entry stored in the static chain table."]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct ExplorerInfo {
+            pub name: &'static str,
+            pub url: &'static str,
+            pub standard: &'static str,
+            pub icon: Option<&'static str>,
+        }
+
+        impl From<&NativeCurrencyInfo> for NativeCurrency {
+            fn from(info: &NativeCurrencyInfo) -> Self {
+                Self {
+                    name: info.name.to_string(),
+                    symbol: info.symbol.to_string(),
+                    decimals: info.decimals,
+                }
+            }
+        }
+
+        impl From<&ExplorerInfo> for Explorer {
+            fn from(info: &ExplorerInfo) -> Self {
+                Self {
+                    name: info.name.to_string(),
+                    url: info.url.to_string(),
+                    standard: info.standard.to_string(),
+                    icon: info.icon.map(str::to_string),
+                }
+            }
         }
 
         #[doc = r" The Chain enum represents various blockchain networks."]
+        #[doc = r""]
+        #[doc = r" Each variant's discriminant is its chain ID."]
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+        #[repr(u64)]
         pub enum Chain {
             #enum_variants
         }
 
+        /// Chain ids in ascending order; `CHAIN_VARIANTS` and `CHAIN_INFOS` share this order.
+        static CHAIN_IDS: [u64; #chain_count] = [#(#chain_ids),*];
+
+        static CHAIN_VARIANTS: [Chain; #chain_count] = [#(#chain_variants),*];
+
+        /// Static chain table, in the same order as `CHAIN_IDS`.
+        static CHAIN_INFOS: [ChainInfo; #chain_count] = [
+            #(#chain_info_entries),*
+        ];
+
+        /// SHA-256 (lowercase hex) of the source chains.json this crate was generated from,
+        /// before any chain selection was applied.
+        pub const CHAINS_JSON_SHA256: &str = #chains_sha256;
+
+        /// Where the bundled chain data came from; see [`data_provenance()`].
+        pub(crate) static DATA_PROVENANCE: DataProvenance = DataProvenance {
+            source: #source,
+            kind: #source_kind,
+            retrieved_at: #retrieved_at,
+            sha256: CHAINS_JSON_SHA256,
+            record_count: #record_count,
+            overlays: &[#(#overlays),*],
+        };
+
         static CHAINS_JSON: &str = include_str!(concat!(env!("OUT_DIR"), "/chains.json"));
-        static CHAINS: OnceCell<Vec<ChainRecord>> = OnceCell::new();
+        static CHAINS: OnceCell<ChainRegistry> = OnceCell::new();
+
+        /// Bundled chain records plus an index from chain id to position.
+        struct ChainRegistry {
+            records: Vec<ChainRecord>,
+            by_id: HashMap<u64, usize>,
+        }
+
+        fn registry() -> &'static ChainRegistry {
+            CHAINS.get_or_init(|| {
+                let records: Vec<ChainRecord> = serde_json::from_str(CHAINS_JSON)
+                    .expect("Failed to parse bundled chains.json; try cleaning and rebuilding");
+                let by_id = records
+                    .iter()
+                    .enumerate()
+                    .map(|(index, record)| (record.chain_id, index))
+                    .collect();
+                ChainRegistry { records, by_id }
+            })
+        }
 
         /// Full chain list deserialized from the bundled chains.json.
         pub fn all_chains() -> &'static [ChainRecord] {
-            CHAINS
-                .get_or_init(|| {
-                    serde_json::from_str(CHAINS_JSON)
-                        .expect("Failed to parse bundled chains.json; try cleaning and rebuilding")
-                })
-                .as_slice()
+            registry().records.as_slice()
+        }
+
+        /// Looks up the bundled record for a chain id without scanning the list.
+        pub fn find_record(chain_id: u64) -> Option<&'static ChainRecord> {
+            let registry = registry();
+            registry
+                .by_id
+                .get(&chain_id)
+                .map(|&index| &registry.records[index])
         }
 
         impl Chain {
             /// Returns chain information
-            pub fn info(&self) -> ChainInfo {
+            pub fn info(&self) -> &'static ChainInfo {
+                &CHAIN_INFOS[self.index()]
+            }
+
+            /// Returns the full schema record for this chain
+            pub fn record(&self) -> &'static ChainRecord {
+                find_record(self.id())
+                    .expect("bundled chains.json has a record for every Chain variant")
+            }
+
+            /// Position of this chain in the static tables.
+            fn index(&self) -> usize {
                 match self {
-                    #(#chain_info_entries),*
+                    #chain_indices
                 }
             }
 
             /// Returns the numerical ID of this chain.
             pub fn id(&self) -> u64 {
-                self.info().id
+                *self as u64
             }
 
             /// Returns the canonical name of this chain.
@@ -469,29 +1119,29 @@ fn generate_chain_code(json_str: &str) -> String {
             }
 
             /// Returns a list of RPC URLs for this chain.
-            pub fn rpc_urls(&self) -> Vec<&'static str> {
+            pub fn rpc_urls(&self) -> &'static [&'static str] {
                 self.info().rpc_urls
             }
 
             /// Returns the list of features supported by the chain
-            pub fn features(&self) -> Vec<String> {
+            pub fn features(&self) -> &'static [&'static str] {
                 self.info().features
             }
 
             /// Returns the list of faucet URLs for the chain
-            pub fn faucets(&self) -> Vec<String> {
+            pub fn faucets(&self) -> &'static [&'static str] {
                 self.info().faucets
             }
 
             /// Returns the native currency details (name, symbol, decimals) as a tuple
-            pub fn native_currency(&self) -> (String, String, u8) {
+            pub fn native_currency(&self) -> (&'static str, &'static str, u8) {
                 let currency = self.native_currency_info();
                 (currency.name, currency.symbol, currency.decimals)
             }
 
             /// Returns the native currency as a structured value
-            pub fn native_currency_info(&self) -> NativeCurrency {
-                self.info().native_currency
+            pub fn native_currency_info(&self) -> &'static NativeCurrencyInfo {
+                &self.info().native_currency
             }
 
             /// Returns the information URL for the chain
@@ -504,6 +1154,55 @@ fn generate_chain_code(json_str: &str) -> String {
                 self.info().short_name
             }
 
+            /// Returns the network ID (`net_version`) of the chain
+            pub fn network_id(&self) -> u64 {
+                self.info().network_id
+            }
+
+            /// Returns whether a `net_version` response (decimal, or `0x`-prefixed hex)
+            /// matches this chain's network ID
+            pub fn matches_net_version(&self, net_version: &str) -> bool {
+                let net_version = net_version.trim();
+                let parsed = match net_version
+                    .strip_prefix("0x")
+                    .or_else(|| net_version.strip_prefix("0X"))
+                {
+                    Some(hex) => u64::from_str_radix(hex, 16),
+                    None => net_version.parse::<u64>(),
+                };
+                parsed == Ok(self.network_id())
+            }
+
+            /// Returns the display title of the chain, if different from its name
+            pub fn title(&self) -> Option<&'static str> {
+                self.info().title
+            }
+
+            /// Returns the chain family symbol (the `chain` field), e.g. "ETH"
+            pub fn chain_family(&self) -> &'static str {
+                self.info().chain
+            }
+
+            /// Returns the icon name of the chain, if available
+            pub fn icon(&self) -> Option<&'static str> {
+                self.info().icon
+            }
+
+            /// Returns the block explorers of the chain
+            pub fn explorers(&self) -> &'static [ExplorerInfo] {
+                self.info().explorers
+            }
+
+            /// Returns the ENS registry of the chain, if available
+            pub fn ens(&self) -> Option<&'static EnsInfo> {
+                self.info().ens.as_ref()
+            }
+
+            /// Returns the parent network of the chain, if available
+            pub fn parent(&self) -> Option<&'static ParentInfo> {
+                self.info().parent.as_ref()
+            }
+
             /// Returns the SLIP-44 coin type for the chain, if available
             pub fn slip44(&self) -> Option<i64> {
                 self.info().slip44
@@ -525,11 +1224,20 @@ fn generate_chain_code(json_str: &str) -> String {
 
             /// Initializes `Chain` from a chain ID, returns error if the chain id is not supported
             fn try_from(value: u64) -> Result<Self, Self::Error> {
-                match value {
-                    #(#try_from_arms)*
+                CHAIN_IDS
+                    .binary_search(&value)
+                    .map(|index| CHAIN_VARIANTS[index])
                     // Other chain IDs not supported
-                    _ => Err(ChainIdNotSupported),
-                }
+                    .map_err(|_| ChainIdNotSupported::new(UnsupportedInput::Id(value), ParseRoute::ChainId))
+            }
+        }
+
+        impl TryFrom<&ChainRecord> for Chain {
+            type Error = ChainIdNotSupported;
+
+            /// Initializes `Chain` from a schema record, returns error if the chain id is not supported
+            fn try_from(record: &ChainRecord) -> Result<Self, Self::Error> {
+                Self::try_from(record.chain_id)
             }
         }
 
@@ -538,18 +1246,29 @@ fn generate_chain_code(json_str: &str) -> String {
 
             /// Initializes `Chain` from a chain ID, returns error if the chain id is not supported
             fn try_from(value: U256) -> Result<Self, Self::Error> {
+                let not_supported = || ChainIdNotSupported::new(UnsupportedInput::U256(value), ParseRoute::U256);
                 // Check to avoid panics for large `U256` values
                 if value > U256::from(u64::MAX) {
-                    return Err(ChainIdNotSupported);
+                    return Err(not_supported());
                 }
                 // Convert U256 to u64 using TryFrom trait rather than as_u64 method
                 match u64::try_from(value) {
-                    Ok(id) => Self::try_from(id),
-                    Err(_) => Err(ChainIdNotSupported),
+                    Ok(id) => Self::try_from(id).map_err(|_| not_supported()),
+                    Err(_) => Err(not_supported()),
                 }
             }
         }
 
+        impl Serialize for Chain {
+            /// Serializes as the decimal chain ID; see [`crate::serde_helpers`] for other forms.
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_u64(self.id())
+            }
+        }
+
         impl<'de> Deserialize<'de> for Chain {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
@@ -561,7 +1280,9 @@ fn generate_chain_code(json_str: &str) -> String {
                     type Value = Chain;
 
                     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                        formatter.write_str("a u64 or a string")
+                        formatter.write_str(
+                            "a chain id, or a string with a decimal, hex or CAIP-2 chain id or a short name",
+                        )
                     }
 
                     fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
@@ -571,39 +1292,34 @@ fn generate_chain_code(json_str: &str) -> String {
                         Chain::try_from(value).map_err(E::custom)
                     }
 
+                    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        // TOML and some YAML parsers hand out signed integers
+                        let id = u64::try_from(value)
+                            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))?;
+                        self.visit_u64(id)
+                    }
+
                     fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
                     where
                         E: de::Error,
                     {
-                        Chain::try_from(value.parse::<u64>().map_err(E::custom)?).map_err(E::custom)
+                        value.parse::<Chain>().map_err(E::custom)
                     }
                 }
 
                 deserializer.deserialize_any(NetworkVisitor)
             }
         }
-
-        #[doc = r" Error indicating that a particular chain ID is not supported."]
-        #[derive(Error, Debug)]
-        #[error("chain id not supported")]
-        pub struct ChainIdNotSupported;
     };
 
     generated_code.to_string()
 }
 
 // Returns a list of chains with their data from the chains.json file
-fn get_chains(chains: &[ChainInfo]) -> Vec<ChainData> {
-    // Default chain names (used for known chains to ensure consistent naming)
-    let default_names = HashMap::from([
-        (1, "Mainnet"),
-        (56, "Bnb"),
-        (100, "Gnosis"),
-        (11155111, "Sepolia"),
-        (8453, "Base"),
-        (31337, "Hardhat"),
-    ]);
-
+fn get_chains(chains: &[ChainInfo], variant_names: &BTreeMap<u64, String>) -> Vec<ChainData> {
     // Block times for specific chains
     let block_times = HashMap::from([
         (1, 12_000),        // Ethereum Mainnet: 12 seconds
@@ -616,14 +1332,13 @@ fn get_chains(chains: &[ChainInfo]) -> Vec<ChainData> {
     chains
         .iter()
         .map(|chain| {
-            // Get default enum variant name if it's a known chain, otherwise generate one
-            let name = default_names
-                .get(&chain.chain_id)
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| sanitize_enum_name(&chain.short_name, chain.chain_id));
+            // Variant names are resolved (and locked) up front
+            let name = variant_names[&(chain.chain_id as u64)].clone();
 
-            // Get specific block time for known chains or use default 12 seconds
-            let block_time_ms = *block_times.get(&chain.chain_id).unwrap_or(&12_000);
+            // Prefer an overlay block time, then known chains, then the default 12 seconds
+            let block_time_ms = chain
+                .block_time_ms
+                .unwrap_or_else(|| *block_times.get(&chain.chain_id).unwrap_or(&12_000));
 
             ChainData {
                 id: chain.chain_id as u64,
@@ -641,16 +1356,60 @@ fn get_chains(chains: &[ChainInfo]) -> Vec<ChainData> {
                 native_currency_decimals: chain.native_currency.decimals as u8,
                 slip44: chain.slip44,
                 block_time_ms,
+                status: chain.status.clone(),
+                red_flags: chain.red_flags.clone(),
+                network_id: chain.network_id as u64,
+                title: chain.title.clone(),
+                chain: chain.chain.clone(),
+                ens: chain.ens.clone(),
+                parent: chain.parent.clone(),
             }
         })
         .collect::<Vec<ChainData>>()
 }
 
+fn optional_str(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+/// Reserves `name` for chain `id`, falling back to `{name}{id}` (then
+/// `Chain{id}`) when the name is already taken or not a valid variant name.
+fn claim_variant_name(
+    name: &str,
+    id: u64,
+    taken: &mut HashSet<String>,
+    collisions: &mut Vec<String>,
+) -> String {
+    if is_valid_variant_name(name) && taken.insert(name.to_string()) {
+        return name.to_string();
+    }
+
+    let mut candidate = format!("{name}{id}");
+    if !is_valid_variant_name(&candidate) || taken.contains(&candidate) {
+        candidate = format!("Chain{id}");
+    }
+    while taken.contains(&candidate) {
+        candidate.push('_');
+    }
+    collisions.push(format!("chain {id}: {name:?} -> {candidate}"));
+    taken.insert(candidate.clone());
+    candidate
+}
+
+fn is_valid_variant_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name != "Self"
+}
+
 fn sanitize_enum_name(name: &str, chain_id: i64) -> String {
     let mut filtered: String = name
         .chars()
         .map(|c| {
-            if c.is_alphanumeric() || c == '_' {
+            if c.is_ascii_alphanumeric() || c == '_' {
                 c
             } else {
                 '_'
@@ -665,7 +1424,7 @@ fn sanitize_enum_name(name: &str, chain_id: i64) -> String {
                 let mut chars = word.chars();
                 match chars.next() {
                     None => String::new(),
-                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                    Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
                 }
             } else {
                 String::new()
@@ -673,7 +1432,7 @@ fn sanitize_enum_name(name: &str, chain_id: i64) -> String {
         })
         .collect();
 
-    if filtered.is_empty() || !filtered.chars().next().unwrap().is_alphabetic() {
+    if !filtered.starts_with(|c: char| c.is_ascii_alphabetic()) {
         format!("Chain{chain_id}")
     } else {
         filtered
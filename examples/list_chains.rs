@@ -5,6 +5,7 @@ use chainlist_rs::schema::ChainRecord;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let chains: &[ChainRecord] = chainlist_rs::all_chains();
     println!("Total chains: {}", chains.len());
+    println!("Data: {}", chainlist_rs::data_provenance());
 
     for chain in chains.iter().take(5) {
         let feature_names = chain
@@ -23,20 +23,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     // Full schema access from bundled JSON (no filesystem path needed)
-    let chains = chainlist_rs::all_chains();
-    if let Some(mainnet) = chains.iter().find(|c| c.chain_id == chain.id()) {
-        println!("RPC endpoints: {}", mainnet.rpc_endpoints().len());
-        if let Some(explorer) = mainnet.explorers().first() {
-            println!("First explorer: {} -> {}", explorer.name, explorer.url);
-        }
-        if let Some(parent) = mainnet.parent() {
-            println!("Parent chain: {}", parent.chain);
-            println!("Bridges: {}", parent.bridges().len());
-        } else {
-            println!("Parent chain: <none>");
-        }
-        println!("Full record:\n{}", serde_json::to_string_pretty(mainnet)?);
+    let mainnet = chain.record();
+    println!("RPC endpoints: {}", mainnet.rpc_endpoints().len());
+    if let Some(explorer) = mainnet.explorers().first() {
+        println!("First explorer: {} -> {}", explorer.name, explorer.url);
     }
+    if let Some(parent) = mainnet.parent() {
+        println!("Parent chain: {}", parent.chain);
+        println!("Bridges: {}", parent.bridges().len());
+    } else {
+        println!("Parent chain: <none>");
+    }
+    println!("Full record:\n{}", serde_json::to_string_pretty(mainnet)?);
 
     Ok(())
 }
@@ -18,5 +18,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         params.block_explorer_urls.join(", ")
     );
 
+    // Switch first, fall back to adding the chain on error 4902
+    for payload in chain.switch_chain_flow().payloads(1) {
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+    }
+
     Ok(())
 }
@@ -0,0 +1,102 @@
+//! EIP-3770 chain-specific addresses: `<shortName>:<address>`, e.g.
+//! `eth:0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb`.
+
+use crate::caip::{parse_checked_address, AccountId};
+use crate::{Chain, ChainIdNotSupported};
+use alloy_primitives::{Address, AddressError};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Errors when parsing an EIP-3770 address.
+#[derive(Debug, Error)]
+pub enum ParseChainAddressError {
+    #[error("missing chain prefix in {0:?}, expected <shortName>:<address>")]
+    MissingPrefix(String),
+    #[error(transparent)]
+    UnknownChain(#[from] ChainIdNotSupported),
+    #[error("invalid address {input:?}: {source}")]
+    InvalidAddress {
+        input: String,
+        #[source]
+        source: AddressError,
+    },
+}
+
+/// Address qualified by the chain it lives on (EIP-3770).
+///
+/// The prefix is resolved through the shortName index (case-insensitive);
+/// mixed-case addresses must carry a valid EIP-55 checksum. Formats as
+/// `<shortName>:<checksummed address>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChainAddress {
+    pub chain: Chain,
+    pub address: Address,
+}
+
+impl ChainAddress {
+    /// `address` on `chain`.
+    pub fn new(chain: Chain, address: Address) -> Self {
+        Self { chain, address }
+    }
+}
+
+impl fmt::Display for ChainAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.chain.short_name(), self.address)
+    }
+}
+
+impl FromStr for ChainAddress {
+    type Err = ParseChainAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+        let (prefix, address) = input
+            .split_once(':')
+            .ok_or_else(|| ParseChainAddressError::MissingPrefix(input.to_string()))?;
+        let chain = Chain::from_short_name(prefix)
+            .ok_or_else(|| ChainIdNotSupported::short_name(prefix))?;
+        let address = parse_checked_address(address).map_err(|source| {
+            ParseChainAddressError::InvalidAddress {
+                input: address.to_string(),
+                source,
+            }
+        })?;
+        Ok(Self::new(chain, address))
+    }
+}
+
+impl From<ChainAddress> for AccountId {
+    fn from(address: ChainAddress) -> Self {
+        AccountId::new(address.chain, address.address)
+    }
+}
+
+impl From<AccountId> for ChainAddress {
+    fn from(account: AccountId) -> Self {
+        ChainAddress::new(account.chain, account.address)
+    }
+}
+
+impl Serialize for ChainAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChainAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Chain {
+    /// EIP-3770 address of `address` on this chain.
+    pub fn address(&self, address: Address) -> ChainAddress {
+        ChainAddress::new(*self, address)
+    }
+}
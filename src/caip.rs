@@ -0,0 +1,346 @@
+//! CAIP identifiers for EVM chains.
+//!
+//! - [`ChainId`] (CAIP-2): `eip155:1`
+//! - [`AccountId`] (CAIP-10): `eip155:1:0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb`
+//! - [`AssetId`] (CAIP-19): `eip155:1/erc20:0x6B175474E89094C44Da98b954EedeAC495271d0F`
+//!
+//! Only the `eip155` namespace is supported, and chain references are
+//! validated against the registry: parsing an identifier of a chain that
+//! isn't a [`Chain`] variant fails with [`ChainIdNotSupported`].
+
+use crate::parse::EIP155_NAMESPACE;
+use crate::{Chain, ChainIdNotSupported};
+use alloy_primitives::hex::FromHexError;
+use alloy_primitives::{Address, AddressError};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Asset namespaces whose reference is a contract address.
+const ADDRESS_ASSET_NAMESPACES: &[&str] = &["erc20", "erc721", "erc1155"];
+
+/// Errors when parsing CAIP identifiers.
+#[derive(Debug, Error)]
+pub enum CaipError {
+    #[error("malformed CAIP identifier {input:?}: expected {expected}")]
+    Malformed {
+        input: String,
+        expected: &'static str,
+    },
+    #[error("unsupported CAIP-2 namespace {0:?}, expected \"eip155\"")]
+    UnsupportedNamespace(String),
+    #[error("invalid eip155 reference {0:?}: expected a decimal chain id")]
+    InvalidChainReference(String),
+    #[error(transparent)]
+    NotSupported(#[from] ChainIdNotSupported),
+    #[error("invalid address {input:?}: {source}")]
+    InvalidAddress {
+        input: String,
+        #[source]
+        source: AddressError,
+    },
+    #[error("invalid asset namespace {0:?}")]
+    InvalidAssetNamespace(String),
+    #[error("invalid asset reference {0:?}")]
+    InvalidAssetReference(String),
+    #[error("invalid token id {0:?}")]
+    InvalidTokenId(String),
+}
+
+/// CAIP-2 chain identifier, e.g. `eip155:1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChainId(Chain);
+
+impl ChainId {
+    /// CAIP-2 identifier of `chain`.
+    pub fn new(chain: Chain) -> Self {
+        Self(chain)
+    }
+
+    /// Chain the identifier refers to.
+    pub fn chain(&self) -> Chain {
+        self.0
+    }
+}
+
+impl From<Chain> for ChainId {
+    fn from(chain: Chain) -> Self {
+        Self(chain)
+    }
+}
+
+impl From<ChainId> for Chain {
+    fn from(id: ChainId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for ChainId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{EIP155_NAMESPACE}:{}", self.0.id())
+    }
+}
+
+impl FromStr for ChainId {
+    type Err = CaipError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (namespace, reference) = s.split_once(':').ok_or_else(|| CaipError::Malformed {
+            input: s.to_string(),
+            expected: "<namespace>:<reference>",
+        })?;
+        if namespace != EIP155_NAMESPACE {
+            return Err(CaipError::UnsupportedNamespace(namespace.to_string()));
+        }
+        if reference.is_empty() || !reference.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(CaipError::InvalidChainReference(reference.to_string()));
+        }
+        let id = reference
+            .parse::<u64>()
+            .map_err(|_| CaipError::InvalidChainReference(reference.to_string()))?;
+        Ok(Self(Chain::try_from(id)?))
+    }
+}
+
+/// CAIP-10 account identifier, e.g. `eip155:1:0xab16…fcdb`.
+///
+/// Formats the address with its EIP-55 checksum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountId {
+    pub chain: Chain,
+    pub address: Address,
+}
+
+impl AccountId {
+    pub fn new(chain: Chain, address: Address) -> Self {
+        Self { chain, address }
+    }
+
+    pub fn chain_id(&self) -> ChainId {
+        ChainId(self.chain)
+    }
+}
+
+impl fmt::Display for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.chain_id(), self.address)
+    }
+}
+
+impl FromStr for AccountId {
+    type Err = CaipError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (chain_id, address) = s.rsplit_once(':').ok_or_else(|| CaipError::Malformed {
+            input: s.to_string(),
+            expected: "<namespace>:<reference>:<address>",
+        })?;
+        let chain = chain_id.parse::<ChainId>()?.chain();
+        Ok(Self::new(chain, parse_address(address)?))
+    }
+}
+
+/// CAIP-19 asset identifier, e.g. `eip155:1/erc20:0x6B17…1d0F` or
+/// `eip155:1/erc721:0x0601…266d/771769`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AssetId {
+    chain: Chain,
+    namespace: String,
+    reference: String,
+    token_id: Option<String>,
+}
+
+impl AssetId {
+    /// Asset in an arbitrary namespace. Validates the CAIP-19 character sets,
+    /// and the address for `erc20`, `erc721` and `erc1155` assets.
+    pub fn new(
+        chain: Chain,
+        namespace: &str,
+        reference: &str,
+        token_id: Option<&str>,
+    ) -> Result<Self, CaipError> {
+        if !(3..=8).contains(&namespace.len())
+            || !namespace
+                .bytes()
+                .all(|b| b == b'-' || b.is_ascii_lowercase() || b.is_ascii_digit())
+        {
+            return Err(CaipError::InvalidAssetNamespace(namespace.to_string()));
+        }
+
+        let reference = if ADDRESS_ASSET_NAMESPACES.contains(&namespace) {
+            parse_address(reference)?.to_string()
+        } else if is_asset_reference(reference, 128) {
+            reference.to_string()
+        } else {
+            return Err(CaipError::InvalidAssetReference(reference.to_string()));
+        };
+
+        if let Some(token_id) = token_id {
+            if !is_asset_reference(token_id, 78) {
+                return Err(CaipError::InvalidTokenId(token_id.to_string()));
+            }
+        }
+
+        Ok(Self {
+            chain,
+            namespace: namespace.to_string(),
+            reference,
+            token_id: token_id.map(str::to_string),
+        })
+    }
+
+    /// ERC-20 token.
+    pub fn erc20(chain: Chain, token: Address) -> Self {
+        Self::address_asset(chain, "erc20", token, None)
+    }
+
+    /// ERC-721 token, or the whole collection when `token_id` is `None`.
+    pub fn erc721(chain: Chain, collection: Address, token_id: Option<u64>) -> Self {
+        Self::address_asset(chain, "erc721", collection, token_id)
+    }
+
+    /// Native currency of the chain, as a SLIP-44 asset (`slip44:60`), if the
+    /// chain has a SLIP-44 coin type.
+    pub fn native(chain: Chain) -> Option<Self> {
+        Some(Self {
+            chain,
+            namespace: "slip44".to_string(),
+            reference: chain.slip44()?.to_string(),
+            token_id: None,
+        })
+    }
+
+    fn address_asset(
+        chain: Chain,
+        namespace: &str,
+        address: Address,
+        token_id: Option<u64>,
+    ) -> Self {
+        Self {
+            chain,
+            namespace: namespace.to_string(),
+            reference: address.to_string(),
+            token_id: token_id.map(|id| id.to_string()),
+        }
+    }
+
+    pub fn chain(&self) -> Chain {
+        self.chain
+    }
+
+    pub fn chain_id(&self) -> ChainId {
+        ChainId(self.chain)
+    }
+
+    /// Asset namespace, e.g. `erc20`.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Asset reference, e.g. the token contract address.
+    pub fn reference(&self) -> &str {
+        &self.reference
+    }
+
+    /// Token ID within the asset, for non-fungible tokens.
+    pub fn token_id(&self) -> Option<&str> {
+        self.token_id.as_deref()
+    }
+
+    /// Contract address of `erc20`, `erc721` and `erc1155` assets.
+    pub fn token_address(&self) -> Option<Address> {
+        ADDRESS_ASSET_NAMESPACES
+            .contains(&self.namespace.as_str())
+            .then(|| self.reference.parse().ok())
+            .flatten()
+    }
+}
+
+impl fmt::Display for AssetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}:{}",
+            self.chain_id(),
+            self.namespace,
+            self.reference
+        )?;
+        if let Some(token_id) = &self.token_id {
+            write!(f, "/{token_id}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for AssetId {
+    type Err = CaipError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || CaipError::Malformed {
+            input: s.to_string(),
+            expected: "<chain id>/<asset namespace>:<asset reference>[/<token id>]",
+        };
+        let mut parts = s.splitn(3, '/');
+        let chain_id = parts.next().ok_or_else(malformed)?;
+        let (namespace, reference) = parts
+            .next()
+            .and_then(|asset| asset.split_once(':'))
+            .ok_or_else(malformed)?;
+        let token_id = parts.next();
+
+        let chain = chain_id.parse::<ChainId>()?.chain();
+        Self::new(chain, namespace, reference, token_id)
+    }
+}
+
+/// Parses a `0x`-prefixed address. Mixed-case input must carry a valid EIP-55
+/// checksum; all-lowercase and all-uppercase input is accepted as is.
+pub(crate) fn parse_checked_address(input: &str) -> Result<Address, AddressError> {
+    let hex = input
+        .strip_prefix("0x")
+        .ok_or(AddressError::Hex(FromHexError::InvalidStringLength))?;
+
+    let has_lower = hex.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = hex.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lower && has_upper {
+        Address::parse_checksummed(input, None)
+    } else {
+        Ok(Address::from_str(hex)?)
+    }
+}
+
+fn parse_address(input: &str) -> Result<Address, CaipError> {
+    parse_checked_address(input).map_err(|source| CaipError::InvalidAddress {
+        input: input.to_string(),
+        source,
+    })
+}
+
+/// CAIP-19 reference / token ID character set: `[-.%a-zA-Z0-9]{1,max_len}`.
+fn is_asset_reference(value: &str, max_len: usize) -> bool {
+    (1..=max_len).contains(&value.len())
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'%'))
+}
+
+/// Serializes as the string form and deserializes through `FromStr`.
+macro_rules! string_serde {
+    ($($ty:ty),*) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(de::Error::custom)
+            }
+        }
+    )*};
+}
+
+string_serde!(ChainId, AccountId, AssetId);
@@ -3,6 +3,19 @@
 use crate::schema::{ChainRecord, NativeCurrency};
 use crate::Chain;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// EIP-3085 method adding a chain to the wallet.
+pub const WALLET_ADD_ETHEREUM_CHAIN: &str = "wallet_addEthereumChain";
+
+/// EIP-3326 method switching the wallet's active chain.
+pub const WALLET_SWITCH_ETHEREUM_CHAIN: &str = "wallet_switchEthereumChain";
+
+/// EIP-1193 provider error code: the wallet doesn't know the requested chain.
+pub const UNRECOGNIZED_CHAIN_ERROR: i64 = 4902;
+
+/// EIP-1193 provider error code: the user rejected the request.
+pub const USER_REJECTED_ERROR: i64 = 4001;
 
 /// EIP-3085 wallet addChain parameters.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -19,6 +32,117 @@ pub struct Eip3085Params {
     pub icon_urls: Vec<String>,
 }
 
+/// EIP-3326 wallet switchEthereumChain parameters.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Eip3326Params {
+    /// Hex string chain ID, e.g. "0x1".
+    pub chain_id: String,
+}
+
+/// JSON-RPC 2.0 request carrying a single params object.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonRpcRequest<P> {
+    pub jsonrpc: String,
+    pub id: u64,
+    pub method: String,
+    pub params: Vec<P>,
+}
+
+impl<P> JsonRpcRequest<P> {
+    /// Request for `method` with `params` as its only parameter.
+    pub fn new(id: u64, method: &str, params: P) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            method: method.to_string(),
+            params: vec![params],
+        }
+    }
+}
+
+/// What to do after a `wallet_switchEthereumChain` response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SwitchStep {
+    /// The wallet switched chains.
+    Done,
+    /// The wallet doesn't know the chain (error 4902): send `wallet_addEthereumChain`.
+    AddChain,
+    /// Any other error, e.g. 4001 when the user rejected the switch.
+    Failed { code: Option<i64>, message: String },
+}
+
+/// The standard switch-then-add sequence: request `wallet_switchEthereumChain`
+/// and, if the wallet answers with error 4902, fall back to
+/// `wallet_addEthereumChain`.
+#[derive(Clone, Debug)]
+pub struct SwitchChainFlow {
+    pub switch: Eip3326Params,
+    pub add: Eip3085Params,
+}
+
+impl SwitchChainFlow {
+    pub fn new(switch: Eip3326Params, add: Eip3085Params) -> Self {
+        Self { switch, add }
+    }
+
+    /// `wallet_switchEthereumChain` request.
+    pub fn switch_request(&self, id: u64) -> JsonRpcRequest<Eip3326Params> {
+        JsonRpcRequest::new(id, WALLET_SWITCH_ETHEREUM_CHAIN, self.switch.clone())
+    }
+
+    /// `wallet_addEthereumChain` fallback request.
+    pub fn add_request(&self, id: u64) -> JsonRpcRequest<Eip3085Params> {
+        JsonRpcRequest::new(id, WALLET_ADD_ETHEREUM_CHAIN, self.add.clone())
+    }
+
+    /// Both requests as JSON-RPC payloads, in order, with ids `first_id` and `first_id + 1`.
+    pub fn payloads(&self, first_id: u64) -> [Value; 2] {
+        [
+            serde_json::to_value(self.switch_request(first_id)).expect("params serialize"),
+            serde_json::to_value(self.add_request(first_id + 1)).expect("params serialize"),
+        ]
+    }
+
+    /// Next step given the wallet's answer to the switch request: a JSON-RPC
+    /// response, or a bare EIP-1193 error object (`{"code": 4902, ...}`).
+    pub fn after_switch(&self, response: &Value) -> SwitchStep {
+        let error = match response.get("error") {
+            Some(error) if !error.is_null() => error,
+            Some(_) => return SwitchStep::Done,
+            None if response.get("code").is_some() => response,
+            None => return SwitchStep::Done,
+        };
+
+        let code = provider_error_code(error);
+        if code == Some(UNRECOGNIZED_CHAIN_ERROR) {
+            return SwitchStep::AddChain;
+        }
+        SwitchStep::Failed {
+            code,
+            message: error
+                .get("message")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
+
+/// EIP-1193 error code of a provider error object.
+///
+/// Some wallets (e.g. MetaMask mobile) wrap the original error, so
+/// `data.originalError.code` is checked when it holds 4902.
+pub fn provider_error_code(error: &Value) -> Option<i64> {
+    let wrapped = error
+        .pointer("/data/originalError/code")
+        .and_then(Value::as_i64);
+    if wrapped == Some(UNRECOGNIZED_CHAIN_ERROR) {
+        return wrapped;
+    }
+    error.get("code").and_then(Value::as_i64).or(wrapped)
+}
+
 impl Eip3085Params {
     fn from_parts(
         chain_id: u64,
@@ -45,21 +169,33 @@ impl Chain {
         format!("0x{:x}", self.id())
     }
 
+    /// EIP-3326 wallet switchEthereumChain parameters.
+    pub fn to_eip3326(&self) -> Eip3326Params {
+        Eip3326Params {
+            chain_id: self.chain_id_hex(),
+        }
+    }
+
+    /// Switch-then-add request sequence for this chain.
+    pub fn switch_chain_flow(&self) -> SwitchChainFlow {
+        SwitchChainFlow::new(self.to_eip3326(), self.to_eip3085())
+    }
+
     /// Convert to EIP-3085 wallet parameters.
     pub fn to_eip3085(&self) -> Eip3085Params {
         let info = self.info();
         let explorer_urls: Vec<String> = info
             .explorers
             .iter()
-            .filter(|e| e.standard == "EIP3091" || e.standard.is_empty())
-            .map(|e| e.url.clone())
+            .filter(|e| e.is_eip3091() || e.standard.is_empty())
+            .map(|e| e.url.to_string())
             .collect();
-        let icon_urls = info.icon.iter().cloned().collect::<Vec<_>>();
+        let icon_urls = info.icon.iter().map(|s| s.to_string()).collect::<Vec<_>>();
         Eip3085Params::from_parts(
             info.id,
             info.name,
-            &info.native_currency,
-            &info.rpc_urls,
+            &NativeCurrency::from(&info.native_currency),
+            info.rpc_urls,
             &explorer_urls,
             &icon_urls,
         )
@@ -72,12 +208,24 @@ impl ChainRecord {
         format!("0x{:x}", self.chain_id)
     }
 
+    /// EIP-3326 wallet switchEthereumChain parameters.
+    pub fn to_eip3326(&self) -> Eip3326Params {
+        Eip3326Params {
+            chain_id: self.chain_id_hex(),
+        }
+    }
+
+    /// Switch-then-add request sequence for this chain.
+    pub fn switch_chain_flow(&self) -> SwitchChainFlow {
+        SwitchChainFlow::new(self.to_eip3326(), self.to_eip3085())
+    }
+
     /// Convert schema record to EIP-3085 wallet parameters.
     pub fn to_eip3085(&self) -> Eip3085Params {
         let explorer_urls: Vec<String> = self
             .explorers
             .iter()
-            .filter(|e| e.standard == "EIP3091" || e.standard.is_empty())
+            .filter(|e| e.is_eip3091() || e.standard.is_empty())
             .map(|e| e.url.clone())
             .collect();
         let icon_urls = self.icon.iter().cloned().collect::<Vec<_>>();
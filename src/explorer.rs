@@ -0,0 +1,123 @@
+//! EIP-3091 block explorer links.
+//!
+//! Explorers advertising the `EIP3091` standard serve `tx/<hash>`,
+//! `address/<address>`, `block/<number>` and `token/<address>` pages under
+//! their base URL.
+
+use crate::schema::{ChainRecord, Explorer};
+use crate::{Chain, ExplorerInfo};
+use alloy_primitives::{Address, B256};
+use std::fmt;
+
+/// Standard name of EIP-3091 explorers in `chains.json`.
+pub const EIP3091: &str = "EIP3091";
+
+/// Page on an EIP-3091 explorer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExplorerTarget {
+    /// Transaction by hash.
+    Tx(B256),
+    /// Account or contract.
+    Address(Address),
+    /// Block by number.
+    Block(u64),
+    /// Token contract.
+    Token(Address),
+}
+
+impl ExplorerTarget {
+    /// Path segment of the page, e.g. `tx`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Tx(_) => "tx",
+            Self::Address(_) => "address",
+            Self::Block(_) => "block",
+            Self::Token(_) => "token",
+        }
+    }
+}
+
+/// Formats as the path relative to the explorer, e.g. `block/123`.
+impl fmt::Display for ExplorerTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tx(hash) => write!(f, "{}/{hash}", self.kind()),
+            Self::Address(address) | Self::Token(address) => {
+                write!(f, "{}/{address}", self.kind())
+            }
+            Self::Block(number) => write!(f, "{}/{number}", self.kind()),
+        }
+    }
+}
+
+fn link(base_url: &str, target: ExplorerTarget) -> String {
+    format!("{}/{target}", base_url.trim_end_matches('/'))
+}
+
+impl ExplorerInfo {
+    /// Whether the explorer follows EIP-3091 URL conventions.
+    pub fn is_eip3091(&self) -> bool {
+        self.standard.eq_ignore_ascii_case(EIP3091)
+    }
+
+    /// Link to `target` on this explorer. Only meaningful for EIP-3091 explorers.
+    pub fn url_for(&self, target: ExplorerTarget) -> String {
+        link(self.url, target)
+    }
+}
+
+impl Explorer {
+    /// Whether the explorer follows EIP-3091 URL conventions.
+    pub fn is_eip3091(&self) -> bool {
+        self.standard.eq_ignore_ascii_case(EIP3091)
+    }
+
+    /// Link to `target` on this explorer. Only meaningful for EIP-3091 explorers.
+    pub fn url_for(&self, target: ExplorerTarget) -> String {
+        link(&self.url, target)
+    }
+}
+
+impl Chain {
+    /// EIP-3091 explorers of the chain, preferred first.
+    pub fn eip3091_explorers(&self) -> impl Iterator<Item = &'static ExplorerInfo> {
+        self.explorers().iter().filter(|e| e.is_eip3091())
+    }
+
+    /// Link to `target` on the preferred EIP-3091 explorer.
+    pub fn explorer_url(&self, target: ExplorerTarget) -> Option<String> {
+        self.eip3091_explorers()
+            .next()
+            .map(|explorer| explorer.url_for(target))
+    }
+
+    /// Link to `target` on the EIP-3091 explorer called `name`
+    /// (case-insensitive, e.g. "blockscout").
+    pub fn explorer_url_on(&self, name: &str, target: ExplorerTarget) -> Option<String> {
+        self.eip3091_explorers()
+            .find(|explorer| explorer.name.eq_ignore_ascii_case(name))
+            .map(|explorer| explorer.url_for(target))
+    }
+}
+
+impl ChainRecord {
+    /// EIP-3091 explorers of the chain, preferred first.
+    pub fn eip3091_explorers(&self) -> impl Iterator<Item = &Explorer> {
+        self.explorers.iter().filter(|e| e.is_eip3091())
+    }
+
+    /// Link to `target` on the preferred EIP-3091 explorer.
+    pub fn explorer_url(&self, target: ExplorerTarget) -> Option<String> {
+        self.eip3091_explorers()
+            .next()
+            .map(|explorer| explorer.url_for(target))
+    }
+
+    /// Link to `target` on the EIP-3091 explorer called `name`
+    /// (case-insensitive, e.g. "blockscout").
+    pub fn explorer_url_on(&self, name: &str, target: ExplorerTarget) -> Option<String> {
+        self.eip3091_explorers()
+            .find(|explorer| explorer.name.eq_ignore_ascii_case(name))
+            .map(|explorer| explorer.url_for(target))
+    }
+}
@@ -0,0 +1,119 @@
+//! Typed chain feature flags.
+//!
+//! `chains.json` lists features as free-form names. The known EIPs map to
+//! dedicated variants; anything else is kept as [`ChainFeature::Other`].
+
+use crate::schema::{ChainRecord, Feature};
+use crate::Chain;
+use std::fmt;
+use std::str::FromStr;
+
+/// Feature advertised by a chain.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChainFeature {
+    /// EIP-155: replay-protected transactions.
+    Eip155,
+    /// EIP-1559: fee market.
+    Eip1559,
+    /// EIP-1108: reduced alt_bn128 precompile gas costs.
+    Eip1108,
+    /// EIP-6551: token bound accounts.
+    Eip6551,
+    /// EIP-55: mixed-case checksum addresses.
+    Eip55,
+    /// EIP-20: token standard.
+    Eip20,
+    /// Any other feature name, kept verbatim.
+    Other(String),
+}
+
+impl ChainFeature {
+    /// Feature name as it appears in `chains.json`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Eip155 => "EIP155",
+            Self::Eip1559 => "EIP1559",
+            Self::Eip1108 => "EIP1108",
+            Self::Eip6551 => "EIP6551",
+            Self::Eip55 => "EIP55",
+            Self::Eip20 => "EIP20",
+            Self::Other(name) => name,
+        }
+    }
+
+    /// Whether a raw feature name refers to this feature (ASCII case-insensitive).
+    pub fn matches(&self, name: &str) -> bool {
+        name.trim().eq_ignore_ascii_case(self.as_str())
+    }
+}
+
+impl From<&str> for ChainFeature {
+    fn from(name: &str) -> Self {
+        let name = name.trim();
+        [
+            Self::Eip155,
+            Self::Eip1559,
+            Self::Eip1108,
+            Self::Eip6551,
+            Self::Eip55,
+            Self::Eip20,
+        ]
+        .into_iter()
+        .find(|feature| feature.matches(name))
+        .unwrap_or_else(|| Self::Other(name.to_string()))
+    }
+}
+
+impl From<String> for ChainFeature {
+    fn from(name: String) -> Self {
+        Self::from(name.as_str())
+    }
+}
+
+impl FromStr for ChainFeature {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl fmt::Display for ChainFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Feature {
+    /// Typed view of this feature.
+    pub fn kind(&self) -> ChainFeature {
+        ChainFeature::from(self.name.as_str())
+    }
+}
+
+impl Chain {
+    /// Typed features supported by the chain.
+    pub fn chain_features(&self) -> Vec<ChainFeature> {
+        self.features()
+            .iter()
+            .map(|name| ChainFeature::from(*name))
+            .collect()
+    }
+
+    /// Whether the chain advertises `feature`.
+    pub fn supports(&self, feature: ChainFeature) -> bool {
+        self.features().iter().any(|name| feature.matches(name))
+    }
+}
+
+impl ChainRecord {
+    /// Typed features supported by the chain.
+    pub fn chain_features(&self) -> Vec<ChainFeature> {
+        self.features.iter().map(Feature::kind).collect()
+    }
+
+    /// Whether the chain advertises `feature`.
+    pub fn supports(&self, feature: ChainFeature) -> bool {
+        self.features.iter().any(|f| feature.matches(&f.name))
+    }
+}
@@ -4,11 +4,52 @@
 //!
 //! ## Build-time data
 //!
-//! By default the build script downloads `chains.json` from
-//! `https://chainid.network/chains.json`. This requires network access during
-//! compilation. Override with:
-//! - `CHAINS_JSON_URL` to point to another source.
-//! - `CHAINS_JSON_PATH` to supply a local file and skip the download.
+//! By default the build script uses the `data/chains.json` snapshot shipped
+//! with the crate and never touches the network, so builds are reproducible.
+//! - The `network` feature opts into downloading `chains.json` from
+//!   `https://chainid.network/chains.json` (or `CHAINS_JSON_URL`). Downloads
+//!   are cached in the build directory for two hours; `CHAINS_JSON_REFRESH=1`
+//!   forces a fresh one. The committed snapshot is never overwritten, and the
+//!   `offline` feature always wins.
+//! - `CHAINS_JSON_PATH` supplies a local file instead.
+//!
+//! To pin the data, set `CHAINS_JSON_SHA256` or commit a `chains.json.sha256`
+//! next to the chains.json in use (e.g. `data/chains.json.sha256`). The build
+//! fails if the fetched or local file doesn't match. The digest of the data
+//! actually used is available as [`CHAINS_JSON_SHA256`].
+//!
+//! [`data_provenance()`] reports the source, retrieval time, digest and record
+//! count of the bundled data at runtime.
+//!
+//! ## Private chains
+//!
+//! `CHAINS_OVERLAY_PATH` lists one or more JSON files (separated like `PATH`)
+//! in the chains.json schema that are merged in before code generation. Entries
+//! with a new `chainId` add chains; entries with an existing `chainId` override
+//! only the fields they set. Overlay entries may also set `blockTimeMs`.
+//! Overlaid chains become regular [`Chain`] variants and appear in
+//! [`all_chains()`]. Chains added by an overlay are always kept when
+//! selecting chains; overridden upstream chains follow the selection.
+//!
+//! ## Selecting chains
+//!
+//! By default every chain in `chains.json` becomes a [`Chain`] variant. To
+//! generate only a subset (smaller enum, faster builds, smaller binaries):
+//! - `CHAINLIST_INCLUDE=1,10,8453,42161` selects chain IDs explicitly.
+//! - `CHAINLIST_INCLUDE=mainnets,major-l2s,testnets` selects curated sets.
+//!
+//! IDs and set names can be mixed. [`Chain`], [`all_chains()`] and the
+//! embedded JSON then only contain the selected chains. The selection is an
+//! environment variable rather than a Cargo feature because it removes
+//! variants; set it for the final binary, never from a library. This crate's
+//! own examples and tests expect at least the curated sets.
+//!
+//! ## Stable variant names
+//!
+//! Variant names are pinned per chain ID in the committed `data/chains.lock`.
+//! When upstream renames a chain's `shortName`, the existing variant keeps its
+//! name. Chains missing from the lockfile get a derived name and a build
+//! warning; `CHAINLIST_UPDATE_LOCK=1` records new upstream chains in it.
 //!
 //! ## Examples
 //!
@@ -21,18 +62,39 @@
 //! ```
 
 use alloy_primitives::U256;
-use serde::{de, Deserialize, Deserializer};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::time::Duration;
-use thiserror::Error;
 
+pub mod address;
+pub mod caip;
 pub mod eip;
+pub mod explorer;
+pub mod feature;
+pub mod parse;
+pub mod provenance;
+pub mod query;
+pub mod resolve;
 pub mod schema;
+pub mod search;
+pub mod serde_helpers;
+pub mod status;
+pub mod wallet;
+
+pub use address::ChainAddress;
+pub use explorer::ExplorerTarget;
+pub use feature::ChainFeature;
+pub use parse::{ChainIdNotSupported, ParseChainError};
+pub use provenance::{data_provenance, DataProvenance};
+pub use query::ChainQuery;
+pub use status::{ChainStatus, RedFlag};
 
 include!(concat!(env!("OUT_DIR"), "/chain_generated.rs"));
 
 #[cfg(test)]
 mod test {
-    use super::{all_chains, Chain};
+    use super::{
+        all_chains, find_record, Chain, ChainFeature, ChainStatus, ParseChainError, RedFlag,
+    };
     use crate::schema;
     use serde_json::Value;
     use std::collections::HashSet;
@@ -137,6 +199,833 @@ mod test {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn parse_from_str() {
+        let base = Chain::Base;
+        assert_eq!("8453".parse::<Chain>().unwrap(), base);
+        assert_eq!("0x2105".parse::<Chain>().unwrap(), base);
+        assert_eq!("eip155:8453".parse::<Chain>().unwrap(), base);
+        assert_eq!("base".parse::<Chain>().unwrap(), base);
+        assert_eq!(" BASE ".parse::<Chain>().unwrap(), base);
+        assert_eq!(base.caip2(), "eip155:8453");
+        assert_eq!(Chain::from_short_name("eth"), Some(Chain::Mainnet));
+
+        assert!(matches!("".parse::<Chain>(), Err(ParseChainError::Empty)));
+        assert!(matches!(
+            "0xzz".parse::<Chain>(),
+            Err(ParseChainError::InvalidId { .. })
+        ));
+        for signed in ["0x+1", "eip155:+1"] {
+            assert!(
+                matches!(
+                    signed.parse::<Chain>(),
+                    Err(ParseChainError::InvalidId { .. })
+                ),
+                "{signed}"
+            );
+        }
+        // Every shortName round-trips, including `0x`-prefixed ones like `0xl3`
+        if let Ok(l3x) = Chain::try_from(7117u64) {
+            assert_eq!("0xl3".parse::<Chain>().unwrap(), l3x);
+        }
+        for chain in Chain::iter() {
+            assert_eq!(chain.short_name().parse::<Chain>().unwrap(), chain);
+        }
+        assert!(matches!(
+            "EIP155:1".parse::<Chain>(),
+            Err(ParseChainError::UnsupportedNamespace(_))
+        ));
+        assert!("EIP155:1".parse::<crate::caip::ChainId>().is_err());
+        assert!(matches!(
+            "cosmos:cosmoshub-4".parse::<Chain>(),
+            Err(ParseChainError::UnsupportedNamespace(_))
+        ));
+        assert!(matches!(
+            "18446744073709551615".parse::<Chain>(),
+            Err(ParseChainError::NotSupported(err)) if err.chain_id() == Some(u64::MAX)
+        ));
+        assert!(matches!(
+            "definitely-not-a-chain".parse::<Chain>(),
+            Err(ParseChainError::NotSupported(err)) if err.chain_id().is_none()
+        ));
+        // The `chain` family slug is shared by several Polygon networks
+        match "polygon".parse::<Chain>() {
+            Err(ParseChainError::Ambiguous { candidates, .. }) => {
+                assert!(candidates.contains(&Chain::try_from(137).unwrap()))
+            }
+            other => panic!("expected an ambiguous match, got {other:?}"),
+        }
+
+        // Deserialize accepts the same forms
+        let chain: Chain = serde_json::from_str("\"eip155:1\"").unwrap();
+        assert_eq!(chain, Chain::Mainnet);
+        let chain: Chain = serde_json::from_str("\"0x1\"").unwrap();
+        assert_eq!(chain, Chain::Mainnet);
+    }
+
+    #[test]
+    fn fuzzy_search_ranks_matches() {
+        use crate::search::{search, search_with, MatchField, SearchOptions};
+
+        let hits = search("arbitrum nova");
+        assert_eq!(hits[0].chain.id(), 42170);
+        assert_eq!(hits[0].field, MatchField::Name);
+
+        let hits = search("polygon zk");
+        assert_eq!(hits[0].chain.id(), 1101);
+
+        // Typos still find the chain
+        let hits = search("arbitrm one");
+        assert_eq!(hits[0].chain.id(), 42161);
+
+        // Short names match exactly
+        assert_eq!(search("arb1")[0].chain.id(), 42161);
+
+        let mainnets_only = SearchOptions {
+            testnet: Some(false),
+            ..SearchOptions::default()
+        };
+        let hits = search_with("sepolia", &mainnets_only);
+        assert!(hits.iter().all(|hit| !hit.chain.is_testnet()));
+        assert!(Chain::Sepolia.is_testnet());
+        assert!(!Chain::Mainnet.is_testnet());
+
+        let active = SearchOptions {
+            status: Some(ChainStatus::Active),
+            ..SearchOptions::default()
+        };
+        assert!(search_with("polygon", &active)
+            .iter()
+            .all(|hit| hit.chain.status() == ChainStatus::Active));
+
+        assert!(search("   ").is_empty());
+    }
+
+    #[test]
+    fn testnets_follow_registry_data() {
+        // Mainnets whose names carry testnet markers
+        let mut record = Chain::Mainnet.record().clone();
+        for (name, short_name) in [("Fuji Mainnet", "fuji"), ("Devnet Labs Mainnet", "devnet")] {
+            record.name = name.to_string();
+            record.short_name = short_name.to_string();
+            assert!(!record.is_testnet(), "{name}");
+        }
+        // Coin type 1 marks a testnet the name doesn't give away, but not a
+        // chain explicitly named "Mainnet"
+        if let (Some(moonbase), Some(ethstorage)) = (find_record(1287), find_record(3339)) {
+            assert_eq!((moonbase.slip44, ethstorage.slip44), (Some(1), Some(1)));
+            assert!(moonbase.is_testnet());
+            assert!(!ethstorage.is_testnet());
+        }
+
+        for chain in [Chain::Mainnet, Chain::Base] {
+            assert!(!chain.is_testnet(), "{chain:?}");
+            assert!(!chain.record().is_testnet(), "{chain:?}");
+        }
+        assert!(Chain::Sepolia.record().is_testnet());
+    }
+
+    #[test]
+    fn query_filters_chains_and_records() {
+        use super::ChainQuery;
+
+        let l2s = ChainQuery::new()
+            .parent(Chain::Mainnet)
+            .status(ChainStatus::Active)
+            .has_explorer("eip3091");
+
+        let chains = l2s.chains().collect::<Vec<_>>();
+        assert!(chains.contains(&Chain::try_from(42161u64).unwrap()));
+        assert!(!chains.contains(&Chain::Mainnet));
+        for chain in &chains {
+            assert_eq!(chain.parent().and_then(|p| p.chain_id()), Some(1));
+        }
+
+        let eip1559 = ChainQuery::new()
+            .feature(ChainFeature::Eip1559)
+            .chains()
+            .collect::<Vec<_>>();
+        assert!(eip1559.contains(&Chain::Mainnet));
+        assert!(!eip1559.contains(&Chain::Base));
+        assert!(eip1559
+            .iter()
+            .all(|chain| chain.supports(ChainFeature::Eip1559)));
+        // Filters combine
+        assert!(l2s
+            .clone()
+            .feature(ChainFeature::Eip1559)
+            .chains()
+            .all(|chain| eip1559.contains(&chain) && chains.contains(&chain)));
+
+        // Chains and records agree
+        let record_ids = l2s.records().map(|r| r.chain_id).collect::<HashSet<_>>();
+        let chain_ids = chains.iter().map(Chain::id).collect::<HashSet<_>>();
+        assert_eq!(record_ids, chain_ids);
+
+        // Arbitrary iterators can be filtered too
+        let subset = [Chain::Mainnet, Chain::try_from(42161u64).unwrap()];
+        assert_eq!(l2s.filter(&subset).count(), 1);
+
+        assert_eq!(ChainQuery::new().chains().count(), Chain::iter().count());
+        assert!(ChainQuery::new()
+            .testnet(true)
+            .chains()
+            .all(|chain| chain.is_testnet()));
+    }
+
+    #[test]
+    fn unsupported_chain_errors_explain_themselves() {
+        use crate::parse::{ParseRoute, UnsupportedInput};
+        use alloy_primitives::U256;
+
+        let arbitrum = Chain::try_from(42161u64).unwrap();
+
+        let err = Chain::try_from(42162u64).unwrap_err();
+        assert_eq!(err.input(), &UnsupportedInput::Id(42162));
+        assert_eq!(err.route(), ParseRoute::ChainId);
+        assert_eq!(err.suggestions()[0], arbitrum);
+        assert!(err.to_string().starts_with("chain id 42162 not supported"));
+        assert!(err.to_string().contains("arb1 (42161)"));
+
+        let err = Chain::try_from(U256::MAX).unwrap_err();
+        assert_eq!(err.route(), ParseRoute::U256);
+        assert_eq!(err.chain_id(), None);
+        assert!(err.suggestions().is_empty());
+
+        match "0xa4b2".parse::<Chain>() {
+            Err(ParseChainError::NotSupported(err)) => {
+                assert_eq!(err.route(), ParseRoute::Hex);
+                assert_eq!(err.chain_id(), Some(0xa4b2));
+                assert!(err.to_string().contains("\"0xa4b2\""));
+            }
+            other => panic!("expected an unsupported chain, got {other:?}"),
+        }
+
+        match "arbitrum nva".parse::<Chain>() {
+            Err(ParseChainError::NotSupported(err)) => {
+                assert_eq!(err.route(), ParseRoute::Name);
+                // Formatting doesn't run the fuzzy search
+                assert!(!err.to_string().contains("did you mean"));
+                assert_eq!(err.suggestions()[0].id(), 42170);
+            }
+            other => panic!("expected an unsupported chain, got {other:?}"),
+        }
+
+        // Deserialize reports the same details
+        let err = serde_json::from_str::<Chain>("42162").unwrap_err();
+        assert!(err.to_string().contains("did you mean arb1 (42161)"));
+        let err = serde_json::from_str::<Chain>("\"arbitrum nva\"").unwrap_err();
+        assert!(err.to_string().contains("\"arbitrum nva\" not supported"));
+    }
+
+    #[test]
+    fn serde_representations_round_trip() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Config {
+            default: Chain,
+            #[serde(with = "crate::serde_helpers::hex")]
+            hex: Chain,
+            #[serde(with = "crate::serde_helpers::short_name")]
+            short_name: Chain,
+            #[serde(with = "crate::serde_helpers::caip2")]
+            caip2: Chain,
+        }
+
+        let base = Chain::try_from(8453u64).unwrap();
+        let config = Config {
+            default: base,
+            hex: base,
+            short_name: base,
+            caip2: base,
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            json,
+            r#"{"default":8453,"hex":"0x2105","short_name":"base","caip2":"eip155:8453"}"#
+        );
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+        let toml = toml::to_string(&config).unwrap();
+        assert!(toml.contains("default = 8453"));
+        assert_eq!(toml::from_str::<Config>(&toml).unwrap(), config);
+
+        // Helpers accept any representation when reading
+        let mixed = r#"{"default":"base","hex":8453,"short_name":"0x2105","caip2":"base"}"#;
+        assert_eq!(serde_json::from_str::<Config>(mixed).unwrap(), config);
+
+        // Every chain round-trips through every representation, including
+        // shortNames that look like hex such as `0xl3`
+        for chain in Chain::iter() {
+            let config = Config {
+                default: chain,
+                hex: chain,
+                short_name: chain,
+                caip2: chain,
+            };
+            let json = serde_json::to_string(&config).unwrap();
+            assert_eq!(
+                serde_json::from_str::<Config>(&json).unwrap(),
+                config,
+                "{json}"
+            );
+        }
+
+        assert!(serde_json::from_str::<Chain>("-1").is_err());
+    }
+
+    #[test]
+    fn caip_identifiers_round_trip() {
+        use crate::caip::{AccountId, AssetId, CaipError, ChainId};
+        use alloy_primitives::address;
+
+        let chain_id: ChainId = "eip155:1".parse().unwrap();
+        assert_eq!(Chain::from(chain_id), Chain::Mainnet);
+        assert_eq!(ChainId::from(Chain::Mainnet).to_string(), "eip155:1");
+        assert!(matches!(
+            "cosmos:cosmoshub-4".parse::<ChainId>(),
+            Err(CaipError::UnsupportedNamespace(_))
+        ));
+        assert!(matches!(
+            "eip155:0x1".parse::<ChainId>(),
+            Err(CaipError::InvalidChainReference(_))
+        ));
+        assert!(matches!(
+            "eip155:18446744073709551615".parse::<ChainId>(),
+            Err(CaipError::NotSupported(_))
+        ));
+
+        let checksummed = "eip155:1:0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb";
+        let account: AccountId = checksummed.parse().unwrap();
+        assert_eq!(
+            account.address,
+            address!("ab16a96d359ec26a11e2c2b3d8f8b8942d5bfcdb")
+        );
+        assert_eq!(account.to_string(), checksummed);
+        // Lowercase is accepted, a broken checksum is not
+        assert_eq!(
+            checksummed.to_lowercase().parse::<AccountId>().unwrap(),
+            account
+        );
+        assert!(matches!(
+            "eip155:1:0xAb16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb".parse::<AccountId>(),
+            Err(CaipError::InvalidAddress { .. })
+        ));
+
+        let dai = "eip155:1/erc20:0x6B175474E89094C44Da98b954EedeAC495271d0F";
+        let asset: AssetId = dai.parse().unwrap();
+        assert_eq!(asset.chain(), Chain::Mainnet);
+        assert_eq!(asset.namespace(), "erc20");
+        assert_eq!(
+            asset.token_address(),
+            Some(address!("6b175474e89094c44da98b954eedeac495271d0f"))
+        );
+        assert_eq!(asset.to_string(), dai);
+
+        let nft = "eip155:1/erc721:0x06012c8cf97BEaD5deAe237070F9587f8E7A266d/771769";
+        let asset: AssetId = nft.parse().unwrap();
+        assert_eq!(asset.token_id(), Some("771769"));
+        assert_eq!(asset.to_string(), nft);
+
+        assert_eq!(
+            AssetId::native(Chain::Mainnet).unwrap().to_string(),
+            "eip155:1/slip44:60"
+        );
+        assert!(matches!(
+            "eip155:1/ERC20:0x6B175474E89094C44Da98b954EedeAC495271d0F".parse::<AssetId>(),
+            Err(CaipError::InvalidAssetNamespace(_))
+        ));
+
+        let json = serde_json::to_string(&account).unwrap();
+        assert_eq!(json, format!("\"{checksummed}\""));
+        assert_eq!(serde_json::from_str::<AccountId>(&json).unwrap(), account);
+    }
+
+    #[test]
+    fn eip3770_addresses_round_trip() {
+        use crate::address::{ChainAddress, ParseChainAddressError};
+        use crate::caip::AccountId;
+        use alloy_primitives::address;
+
+        let safe = address!("ab16a96d359ec26a11e2c2b3d8f8b8942d5bfcdb");
+        let parsed: ChainAddress = "eth:0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb"
+            .parse()
+            .unwrap();
+        assert_eq!(parsed, Chain::Mainnet.address(safe));
+        assert_eq!(
+            parsed.to_string(),
+            "eth:0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb"
+        );
+
+        // Prefix is case-insensitive, lowercase addresses skip the checksum
+        let base = Chain::try_from(8453u64).unwrap();
+        let parsed: ChainAddress = "BASE:0xab16a96d359ec26a11e2c2b3d8f8b8942d5bfcdb"
+            .parse()
+            .unwrap();
+        assert_eq!(parsed, base.address(safe));
+        assert_eq!(
+            AccountId::from(parsed).to_string(),
+            "eip155:8453:0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb"
+        );
+
+        assert!(matches!(
+            "0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb".parse::<ChainAddress>(),
+            Err(ParseChainAddressError::MissingPrefix(_))
+        ));
+        assert!(matches!(
+            "nope:0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb".parse::<ChainAddress>(),
+            Err(ParseChainAddressError::UnknownChain(_))
+        ));
+        assert!(matches!(
+            "eth:0xAb16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb".parse::<ChainAddress>(),
+            Err(ParseChainAddressError::InvalidAddress { .. })
+        ));
+        assert!(matches!(
+            "eth:0x1234".parse::<ChainAddress>(),
+            Err(ParseChainAddressError::InvalidAddress { .. })
+        ));
+
+        let json = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(serde_json::from_str::<ChainAddress>(&json).unwrap(), parsed);
+    }
+
+    #[test]
+    fn eip3091_explorer_links() {
+        use super::ExplorerTarget;
+        use alloy_primitives::{address, b256};
+
+        let hash = b256!("88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b");
+        assert_eq!(
+            Chain::Mainnet.explorer_url(ExplorerTarget::Tx(hash)).unwrap(),
+            "https://etherscan.io/tx/0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b"
+        );
+
+        let token = address!("6b175474e89094c44da98b954eedeac495271d0f");
+        assert_eq!(
+            Chain::Mainnet
+                .explorer_url_on("Blockscout", ExplorerTarget::Token(token))
+                .unwrap(),
+            "https://eth.blockscout.com/token/0x6B175474E89094C44Da98b954EedeAC495271d0F"
+        );
+        assert_eq!(
+            Chain::Mainnet.explorer_url(ExplorerTarget::Block(17_000_000)),
+            Some("https://etherscan.io/block/17000000".to_string())
+        );
+        assert!(Chain::Mainnet
+            .explorer_url_on("no such explorer", ExplorerTarget::Block(1))
+            .is_none());
+
+        // Records build the same links
+        let record = Chain::Mainnet.record();
+        let target = ExplorerTarget::Address(token);
+        assert_eq!(
+            record.explorer_url(target),
+            Chain::Mainnet.explorer_url(target)
+        );
+    }
+
+    #[test]
+    fn resolve_explorer_and_rpc_urls() {
+        use super::ExplorerTarget;
+        use crate::resolve::{
+            resolve_explorer_url, resolve_rpc_url, resolve_url, ResolveUrlError, UrlKind,
+        };
+        use alloy_primitives::{address, b256};
+
+        let base = Chain::try_from(8453u64).unwrap();
+        let token = address!("833589fcd6edb6e08f4c7c32d4f71b54bda02913");
+
+        let (chain, target) = resolve_explorer_url(
+            "https://basescan.org/token/0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913?a=1#code",
+        )
+        .unwrap();
+        assert_eq!(chain, base);
+        assert_eq!(target, ExplorerTarget::Token(token));
+
+        let hash = b256!("88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b");
+        let (chain, target) = resolve_explorer_url(
+            "https://base.blockscout.com/tx/0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b/internal-transactions",
+        )
+        .unwrap();
+        assert_eq!((chain, target), (base, ExplorerTarget::Tx(hash)));
+
+        // Links built by explorer_url resolve back
+        let link = base.explorer_url(ExplorerTarget::Block(1)).unwrap();
+        assert_eq!(
+            resolve_explorer_url(&link).unwrap(),
+            (base, ExplorerTarget::Block(1))
+        );
+
+        // etherscan.io is listed by more than one chain; Ethereum wins
+        let found =
+            resolve_url("etherscan.io/address/0xab16a96d359ec26a11e2c2b3d8f8b8942d5bfcdb").unwrap();
+        assert_eq!(found.kind, UrlKind::Explorer);
+        assert_eq!(found.chain, Chain::Mainnet);
+        assert!(matches!(found.target, Some(ExplorerTarget::Address(_))));
+
+        let found = resolve_url("https://www.etherscan.io").unwrap();
+        assert_eq!((found.chain, found.target), (Chain::Mainnet, None));
+        // Default ports are dropped
+        let (chain, target) = resolve_explorer_url(
+            "https://etherscan.io:443/tx/0x0000000000000000000000000000000000000000000000000000000000000001",
+        )
+        .unwrap();
+        assert_eq!(chain, Chain::Mainnet);
+        assert!(matches!(target, ExplorerTarget::Tx(_)));
+        assert!(matches!(
+            resolve_explorer_url("https://etherscan.io/"),
+            Err(ResolveUrlError::NoTarget(_))
+        ));
+
+        // RPC hosts are matched on host and path, ignoring API key templates
+        let rpc = base.rpc_urls()[0];
+        assert!(resolve_rpc_url(rpc).unwrap().contains(&base));
+        let found = resolve_url(rpc).unwrap();
+        assert!(found.chain == base || found.alternatives.contains(&base));
+
+        assert!(matches!(
+            resolve_url("https://example.invalid/tx/0x00"),
+            Err(ResolveUrlError::Unknown(_))
+        ));
+        assert!(matches!(
+            resolve_url(""),
+            Err(ResolveUrlError::InvalidUrl(_))
+        ));
+    }
+
+    #[test]
+    fn switch_then_add_flow() {
+        use crate::eip::{SwitchStep, WALLET_ADD_ETHEREUM_CHAIN, WALLET_SWITCH_ETHEREUM_CHAIN};
+        use serde_json::json;
+
+        let base = Chain::try_from(8453u64).unwrap();
+        let flow = base.switch_chain_flow();
+        assert_eq!(flow.switch.chain_id, "0x2105");
+
+        let [switch, add] = flow.payloads(7);
+        assert_eq!(
+            switch,
+            json!({
+                "jsonrpc": "2.0",
+                "id": 7,
+                "method": WALLET_SWITCH_ETHEREUM_CHAIN,
+                "params": [{ "chainId": "0x2105" }]
+            })
+        );
+        assert_eq!(add["id"], 8);
+        assert_eq!(add["method"], WALLET_ADD_ETHEREUM_CHAIN);
+        assert_eq!(add["params"][0]["chainId"], "0x2105");
+
+        assert_eq!(
+            flow.after_switch(&json!({ "jsonrpc": "2.0", "id": 7, "result": null })),
+            SwitchStep::Done
+        );
+        assert_eq!(
+            flow.after_switch(&json!({
+                "jsonrpc": "2.0",
+                "id": 7,
+                "error": { "code": 4902, "message": "Unrecognized chain ID \"0x2105\"." }
+            })),
+            SwitchStep::AddChain
+        );
+        // MetaMask mobile wraps the original error
+        assert_eq!(
+            flow.after_switch(&json!({
+                "code": -32603,
+                "message": "Internal error",
+                "data": { "originalError": { "code": 4902 } }
+            })),
+            SwitchStep::AddChain
+        );
+        assert_eq!(
+            flow.after_switch(&json!({ "code": 4001, "message": "User rejected the request." })),
+            SwitchStep::Failed {
+                code: Some(4001),
+                message: "User rejected the request.".to_string()
+            }
+        );
+
+        let record_flow = base.record().switch_chain_flow();
+        assert_eq!(record_flow.switch, flow.switch);
+    }
+
+    #[test]
+    fn sanitize_eip3085_for_wallets() {
+        use crate::wallet::{Eip3085Issue, UrlRejection, MAX_SAFE_CHAIN_ID};
+
+        let mut params = Chain::Mainnet.to_eip3085();
+        params.chain_id = "0x01".to_string();
+        params.chain_name = " Ethereum Mainnet ".to_string();
+        params.rpc_urls = vec![
+            "https://mainnet.infura.io/v3/${INFURA_API_KEY}".to_string(),
+            "wss://eth.drpc.org".to_string(),
+            "http://localhost:8545".to_string(),
+            "https://eth.drpc.org".to_string(),
+            "https://eth.drpc.org".to_string(),
+        ];
+        params.block_explorer_urls = vec![
+            "http://etherscan.io".to_string(),
+            "https://etherscan.io".to_string(),
+        ];
+        params.icon_urls = vec!["ethereum".to_string(), "unknown".to_string()];
+
+        let sanitized = params.sanitize_with(|name| {
+            (name == "ethereum").then(|| "https://icons.example/ethereum.svg".to_string())
+        });
+        assert!(sanitized.is_acceptable());
+        let out = &sanitized.params;
+        assert_eq!(out.chain_id, "0x1");
+        assert_eq!(out.chain_name, "Ethereum Mainnet");
+        assert_eq!(out.rpc_urls, vec!["https://eth.drpc.org"]);
+        assert_eq!(out.block_explorer_urls, vec!["https://etherscan.io"]);
+        assert_eq!(out.icon_urls, vec!["https://icons.example/ethereum.svg"]);
+
+        let issues = &sanitized.issues;
+        assert!(issues.contains(&Eip3085Issue::DroppedRpcUrl {
+            url: "https://mainnet.infura.io/v3/${INFURA_API_KEY}".to_string(),
+            reason: UrlRejection::Template,
+        }));
+        assert!(issues.contains(&Eip3085Issue::DroppedRpcUrl {
+            url: "wss://eth.drpc.org".to_string(),
+            reason: UrlRejection::NotHttps,
+        }));
+        assert!(issues.contains(&Eip3085Issue::DroppedRpcUrl {
+            url: "https://eth.drpc.org".to_string(),
+            reason: UrlRejection::Duplicate,
+        }));
+        assert!(issues.contains(&Eip3085Issue::DroppedIcon {
+            icon: "unknown".to_string()
+        }));
+        assert_eq!(issues.len(), 9);
+
+        // Unfixable problems are fatal
+        params.rpc_urls = vec!["wss://eth.drpc.org".to_string()];
+        params.native_currency.symbol = "TOOLONGSYM".to_string();
+        params.native_currency.decimals = 8;
+        let err = params.sanitize().into_result().unwrap_err();
+        let fatal = err
+            .issues
+            .iter()
+            .filter(|issue| issue.is_fatal())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fatal,
+            vec![
+                &Eip3085Issue::NoRpcUrl,
+                &Eip3085Issue::InvalidSymbol("TOOLONGSYM".to_string()),
+                &Eip3085Issue::InvalidDecimals(8),
+            ]
+        );
+        assert!(err.to_string().contains("no usable RPC URL left"));
+
+        // Chain ids must be within 1..=MAX_SAFE_CHAIN_ID
+        let mut params = Chain::Mainnet.to_eip3085();
+        for (chain_id, issue) in [
+            ("0x0", Eip3085Issue::ZeroChainId),
+            ("0x00", Eip3085Issue::ZeroChainId),
+            (
+                "0x10000000000000",
+                Eip3085Issue::ChainIdTooLarge(MAX_SAFE_CHAIN_ID + 20),
+            ),
+        ] {
+            params.chain_id = chain_id.to_string();
+            let issues = params.sanitize().into_result().unwrap_err().issues;
+            assert!(issues.contains(&issue), "{chain_id}: {issues:?}");
+        }
+        params.chain_id = format!("{MAX_SAFE_CHAIN_ID:#x}");
+        assert!(!params
+            .sanitize()
+            .issues
+            .iter()
+            .any(|issue| matches!(issue, Eip3085Issue::ChainIdTooLarge(_))));
+
+        // Bundled params for Ethereum only need RPC and icon cleanup
+        let sanitized = Chain::Mainnet.to_eip3085().sanitize();
+        assert!(sanitized.is_acceptable());
+        assert!(sanitized
+            .params
+            .rpc_urls
+            .iter()
+            .all(|url| url.starts_with("https://") && !url.contains("${")));
+    }
+
+    #[test]
+    fn verify_untrusted_add_chain_requests() {
+        use crate::wallet::{Eip3085Finding, RiskLevel};
+
+        // Our own params verify cleanly once sanitized (no wss:// RPCs)
+        let base = Chain::try_from(8453u64).unwrap();
+        let verdict = base.to_eip3085().sanitize().params.verify();
+        assert!(verdict.is_verified(), "{:?}", verdict.findings);
+        assert_eq!(verdict.risk(), RiskLevel::Low);
+
+        // Plaintext copies of listed endpoints are flagged
+        let mut plain = base.to_eip3085().sanitize().params;
+        plain.rpc_urls = vec!["http://mainnet.base.org".to_string()];
+        let verdict = plain.verify();
+        assert_eq!(verdict.risk(), RiskLevel::High);
+        assert_eq!(
+            verdict.findings,
+            vec![Eip3085Finding::NonHttpsRpcUrl(
+                "http://mainnet.base.org".to_string()
+            )]
+        );
+        plain.rpc_urls = vec!["https://mainnet.base.org".to_string()];
+        plain.block_explorer_urls = vec!["http://basescan.org".to_string()];
+        assert_eq!(
+            plain.verify().findings,
+            vec![Eip3085Finding::NonHttpsExplorerUrl(
+                "http://basescan.org".to_string()
+            )]
+        );
+
+        // Without RPC URLs nothing was checked
+        plain.rpc_urls.clear();
+        plain.block_explorer_urls.clear();
+        let verdict = plain.verify();
+        assert!(!verdict.is_verified());
+        assert_eq!(verdict.findings, vec![Eip3085Finding::NoRpcUrl]);
+        assert_eq!(verdict.risk(), RiskLevel::Medium);
+
+        // Phishing: known chain id, attacker RPC and spoofed currency
+        let mut params = Chain::Mainnet.to_eip3085();
+        params.chain_name = "Ethereum".to_string();
+        params.rpc_urls = vec![
+            "https://eth.drpc.org".to_string(),
+            "https://rpc.evil.example/eth".to_string(),
+        ];
+        params.native_currency.symbol = "ETHH".to_string();
+        params.native_currency.decimals = 6;
+        let verdict = params.verify();
+        assert_eq!(verdict.chain_id, Some(1));
+        assert_eq!(verdict.risk(), RiskLevel::High);
+        assert!(verdict.findings.contains(&Eip3085Finding::UnknownRpcHost(
+            "rpc.evil.example".to_string()
+        )));
+        assert!(verdict.findings.contains(&Eip3085Finding::SymbolMismatch {
+            expected: "ETH".to_string(),
+            got: "ETHH".to_string()
+        }));
+        assert!(verdict
+            .findings
+            .contains(&Eip3085Finding::DecimalsMismatch {
+                expected: 18,
+                got: 6
+            }));
+        // "Ethereum" is "Ethereum Mainnet" without the generic suffix
+        assert!(!verdict
+            .findings
+            .iter()
+            .any(|f| matches!(f, Eip3085Finding::NameMismatch { .. })));
+
+        params.chain_name = "Ethereum Classic".to_string();
+        assert!(params
+            .verify()
+            .findings
+            .iter()
+            .any(|f| matches!(f, Eip3085Finding::NameMismatch { .. })));
+
+        // Shared RPC hosts must match the chain's own path
+        let mut gnosis = Chain::try_from(100u64).unwrap().to_eip3085();
+        gnosis.rpc_urls = vec!["https://rpc.ankr.com/gnosis".to_string()];
+        assert_eq!(gnosis.verify().risk(), RiskLevel::Low);
+        gnosis.rpc_urls = vec!["https://rpc.ankr.com/eth".to_string()];
+        let verdict = gnosis.verify();
+        assert_eq!(verdict.risk(), RiskLevel::High);
+        assert!(verdict
+            .findings
+            .contains(&Eip3085Finding::UnknownRpcHost("rpc.ankr.com".to_string())));
+
+        // An explicit default port is the same registry host
+        params.rpc_urls = vec!["https://eth.drpc.org:443".to_string()];
+        assert!(!params
+            .verify()
+            .findings
+            .iter()
+            .any(|f| matches!(f, Eip3085Finding::UnknownRpcHost(_))));
+        for url in ["http://eth.drpc.org", "ws://eth.drpc.org"] {
+            params.rpc_urls = vec![url.to_string()];
+            let verdict = params.verify();
+            assert_eq!(verdict.risk(), RiskLevel::High);
+            assert!(verdict
+                .findings
+                .contains(&Eip3085Finding::NonHttpsRpcUrl(url.to_string())));
+        }
+
+        // Red-flagged chains are high risk even with registry data
+        if let Some(flagged) = all_chains().iter().find(|r| !r.red_flags.is_empty()) {
+            let verdict = flagged.to_eip3085().verify();
+            assert_eq!(verdict.risk(), RiskLevel::High);
+            assert!(matches!(
+                verdict.findings[0],
+                Eip3085Finding::RedFlagged { .. }
+            ));
+        }
+
+        params.chain_id = "0xffffffffffff".to_string();
+        let verdict = params.verify();
+        assert_eq!(verdict.risk(), RiskLevel::Medium);
+        assert_eq!(
+            verdict.findings,
+            vec![Eip3085Finding::UnknownChain(0xffffffffffff)]
+        );
+
+        params.chain_id = "one".to_string();
+        assert_eq!(params.verify().risk(), RiskLevel::High);
+    }
+
+    #[cfg(chainlist_selected)]
+    #[test]
+    fn selection_narrows_chains() {
+        let mut expected = HashSet::new();
+        for item in env!("CHAINLIST_INCLUDE").split(',').map(str::trim) {
+            // Mirrors the curated sets in build.rs
+            match item {
+                "mainnets" => expected.extend([1, 25, 56, 100, 137, 250, 1284, 43114]),
+                "major-l2s" => expected.extend([
+                    10, 324, 1101, 5000, 8453, 42161, 42170, 42220, 59144, 81457, 534352,
+                ]),
+                "testnets" => expected.extend([
+                    300, 17000, 59141, 80002, 84532, 421614, 534351, 560048, 11155111, 11155420,
+                ]),
+                id => expected.extend(id.parse::<u64>().ok()),
+            }
+        }
+        // Chains added by an overlay are always kept. Those are complete
+        // records; partial entries only override upstream chains, which
+        // follow the selection.
+        if let Some(paths) = option_env!("CHAINS_OVERLAY_PATH") {
+            for path in std::env::split_paths(paths) {
+                let text = std::fs::read_to_string(&path).unwrap();
+                let entries: Vec<Value> = serde_json::from_str(&text).unwrap();
+                expected.extend(
+                    entries
+                        .iter()
+                        .filter(|e| e.get("name").is_some())
+                        .filter_map(|e| e["chainId"].as_u64()),
+                );
+            }
+        }
+
+        let ids = Chain::iter()
+            .map(|chain| chain.id())
+            .collect::<HashSet<_>>();
+        assert!(!ids.is_empty());
+        assert!(
+            ids.is_subset(&expected),
+            "unselected chains generated: {:?}",
+            ids.difference(&expected).collect::<Vec<_>>()
+        );
+
+        let record_ids = all_chains()
+            .iter()
+            .map(|record| record.chain_id)
+            .collect::<HashSet<_>>();
+        assert_eq!(record_ids, ids);
+    }
+
     #[test]
     fn chains_sorted_and_unique() {
         let ids: Vec<u64> = Chain::iter().map(|c| c.id()).collect();
@@ -157,6 +1046,19 @@ mod test {
         );
     }
 
+    #[test]
+    fn discriminant_is_chain_id() {
+        assert_eq!(Chain::Mainnet as u64, 1);
+        assert_eq!(Chain::Base as u64, 8453);
+
+        for chain in Chain::iter() {
+            assert_eq!(chain.id(), chain.info().id);
+            assert_eq!(Chain::try_from(chain.id()).ok(), Some(chain));
+        }
+
+        assert!(Chain::try_from(u64::MAX).is_err());
+    }
+
     #[test]
     fn chain_records_have_basic_fields() {
         let mut short_names = HashSet::new();
@@ -211,6 +1113,142 @@ mod test {
         }
     }
 
+    #[test]
+    fn static_info_matches_bundled_records() {
+        assert!(std::ptr::eq(Chain::Mainnet.info(), Chain::Mainnet.info()));
+
+        for chain in Chain::iter() {
+            let info = chain.info();
+            let record = chain.record();
+            assert_eq!(info.name, record.name);
+            assert_eq!(info.short_name, record.short_name);
+            assert_eq!(info.rpc_urls, record.rpc.as_slice());
+            assert_eq!(info.explorers.len(), record.explorers.len());
+            assert_eq!(info.native_currency.symbol, record.native_currency.symbol);
+            assert_eq!(info.network_id, record.network_id);
+            assert_eq!(info.title, record.title.as_deref());
+            assert_eq!(info.chain, record.chain);
+            assert_eq!(info.status, record.status.as_deref());
+            assert_eq!(
+                info.ens.map(|ens| ens.registry),
+                record.ens().map(|ens| ens.registry.as_str())
+            );
+            assert_eq!(
+                info.parent
+                    .map(|p| (p.type_field, p.chain, p.bridges.len())),
+                record
+                    .parent()
+                    .map(|p| (p.type_field.as_str(), p.chain.as_str(), p.bridges.len()))
+            );
+        }
+    }
+
+    #[test]
+    fn network_id_and_parent() {
+        assert_eq!(Chain::Mainnet.network_id(), 1);
+        assert!(Chain::Mainnet.matches_net_version("1"));
+        assert!(Chain::Mainnet.matches_net_version("0x1"));
+        assert!(!Chain::Mainnet.matches_net_version("5"));
+        assert_eq!(Chain::Mainnet.chain_family(), "ETH");
+
+        let arbitrum = Chain::try_from(42161).unwrap();
+        let parent = arbitrum.parent().expect("Arbitrum One has a parent");
+        assert_eq!(parent.type_field, "L2");
+        assert_eq!(parent.chain_id(), Some(1));
+    }
+
+    #[test]
+    fn chains_json_digest_is_recorded() {
+        assert_eq!(super::CHAINS_JSON_SHA256.len(), 64);
+        assert!(super::CHAINS_JSON_SHA256
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)));
+    }
+
+    #[test]
+    fn provenance_describes_bundled_data() {
+        use crate::provenance::DataSourceKind;
+
+        let provenance = super::data_provenance();
+        assert_eq!(provenance.record_count, all_chains().len());
+        assert_eq!(provenance.sha256, super::CHAINS_JSON_SHA256);
+        assert!(!provenance.source.is_empty());
+        // Local sources are recorded relative to the crate, without the checkout time
+        if matches!(
+            provenance.kind,
+            DataSourceKind::Snapshot | DataSourceKind::Override
+        ) {
+            assert!(!std::path::Path::new(provenance.source).is_absolute());
+            assert_eq!(
+                provenance.retrieved_at,
+                option_env!("SOURCE_DATE_EPOCH").and_then(|s| s.parse().ok())
+            );
+        }
+        assert!(provenance.to_string().contains(provenance.sha256));
+    }
+
+    #[test]
+    fn typed_features() {
+        assert!(Chain::Mainnet.supports(ChainFeature::Eip1559));
+        assert!(Chain::Mainnet.supports(ChainFeature::Eip155));
+        assert!(!Chain::Mainnet.supports(ChainFeature::Eip6551));
+        assert!(Chain::Mainnet
+            .chain_features()
+            .contains(&ChainFeature::Eip1559));
+
+        assert_eq!(ChainFeature::from("eip1559"), ChainFeature::Eip1559);
+        assert_eq!(
+            ChainFeature::from("Smart Contracts"),
+            ChainFeature::Other("Smart Contracts".to_string())
+        );
+        assert_eq!(ChainFeature::Eip55.to_string(), "EIP55");
+
+        for record in all_chains() {
+            let chain = Chain::try_from(record.chain_id).unwrap();
+            assert_eq!(
+                chain.supports(ChainFeature::Eip1559),
+                record.supports(ChainFeature::Eip1559),
+                "feature mismatch for chain_id {}",
+                record.chain_id
+            );
+        }
+    }
+
+    #[test]
+    fn status_and_red_flags_gate_wallet_offers() {
+        assert_eq!(Chain::Mainnet.status(), ChainStatus::Active);
+        assert!(Chain::Mainnet.red_flag_kinds().is_empty());
+        assert!(Chain::Mainnet.is_safe_to_add());
+
+        assert_eq!(ChainStatus::from("Deprecated"), ChainStatus::Deprecated);
+        assert_eq!(RedFlag::from("reusedChainId"), RedFlag::ReusedChainId);
+
+        for record in all_chains() {
+            let chain = Chain::try_from(record.chain_id).unwrap();
+            assert_eq!(chain.status(), record.status());
+            assert_eq!(chain.red_flag_kinds(), record.red_flag_kinds());
+
+            let expected_safe =
+                record.red_flags.is_empty() && record.status() != ChainStatus::Deprecated;
+            assert_eq!(chain.is_safe_to_add(), expected_safe);
+            assert_eq!(record.is_safe_to_add(), expected_safe);
+        }
+    }
+
+    #[test]
+    fn record_lookup_round_trips() {
+        assert_eq!(Chain::Mainnet.record().chain_id, 1);
+        assert!(find_record(u64::MAX).is_none());
+
+        for record in all_chains() {
+            let found = find_record(record.chain_id).expect("indexed record");
+            assert!(std::ptr::eq(found, record));
+
+            let chain = Chain::try_from(record).expect("Chain for every record");
+            assert!(std::ptr::eq(chain.record(), record));
+        }
+    }
+
     #[test]
     fn schema_loader_matches_bundled_data() {
         let loaded = schema::load_chains().expect("schema::load_chains should succeed");
@@ -0,0 +1,380 @@
+//! Text parsing for [`Chain`].
+//!
+//! [`Chain`] parses from decimal IDs (`8453`), `0x`-prefixed hex IDs
+//! (`0x2105`), CAIP-2 identifiers (`eip155:8453`), shortNames (`base`,
+//! case-insensitive) and, as a last resort, the chain family slug (`chain`
+//! field) when it identifies a single chain.
+
+use crate::search::{levenshtein, search_with, SearchOptions};
+use crate::Chain;
+use alloy_primitives::U256;
+use once_cell::sync::{Lazy, OnceCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+/// CAIP-2 namespace for EVM chains.
+pub const EIP155_NAMESPACE: &str = "eip155";
+
+/// Maximum number of suggestions attached to a [`ChainIdNotSupported`].
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Value that failed to resolve to a [`Chain`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnsupportedInput {
+    /// A numeric chain ID.
+    Id(u64),
+    /// A `U256` chain ID, possibly wider than `u64`.
+    U256(U256),
+    /// Text (a chain ID in some notation, a shortName or a slug).
+    Text(String),
+}
+
+impl UnsupportedInput {
+    /// Chain ID of the input, if it is one that fits in `u64`.
+    pub fn chain_id(&self) -> Option<u64> {
+        match self {
+            Self::Id(id) => Some(*id),
+            Self::U256(value) => u64::try_from(*value).ok(),
+            Self::Text(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for UnsupportedInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Id(id) => write!(f, "chain id {id}"),
+            Self::U256(value) => write!(f, "chain id {value}"),
+            Self::Text(text) => write!(f, "chain {text:?}"),
+        }
+    }
+}
+
+/// How the rejected input was interpreted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseRoute {
+    /// `Chain::try_from(u64)`.
+    ChainId,
+    /// `Chain::try_from(U256)`.
+    U256,
+    /// Decimal chain ID text, e.g. `8453`.
+    Decimal,
+    /// `0x`-prefixed hex chain ID text, e.g. `0x2105`.
+    Hex,
+    /// CAIP-2 identifier, e.g. `eip155:8453`.
+    Caip2,
+    /// shortName, then chain family slug.
+    Name,
+    /// shortName only, e.g. an EIP-3770 address prefix.
+    ShortName,
+}
+
+impl ParseRoute {
+    /// Human-readable description of the route.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ChainId => "chain id",
+            Self::U256 => "U256 chain id",
+            Self::Decimal => "decimal chain id",
+            Self::Hex => "hex chain id",
+            Self::Caip2 => "CAIP-2 chain id",
+            Self::Name => "short name or chain slug",
+            Self::ShortName => "short name",
+        }
+    }
+}
+
+impl fmt::Display for ParseRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error indicating that a chain ID or name doesn't match any supported chain.
+///
+/// Carries the rejected input, how it was parsed, and the closest supported
+/// chains (computed on first use). `Display` only lists suggestions for chain
+/// IDs, which are cheap to find; name suggestions need a fuzzy search over
+/// every chain and are left to [`suggestions`](Self::suggestions).
+#[derive(Clone, Debug)]
+pub struct ChainIdNotSupported {
+    input: UnsupportedInput,
+    route: ParseRoute,
+    chain_id: Option<u64>,
+    suggestions: OnceCell<Vec<Chain>>,
+}
+
+impl ChainIdNotSupported {
+    pub(crate) fn new(input: UnsupportedInput, route: ParseRoute) -> Self {
+        Self {
+            chain_id: input.chain_id(),
+            input,
+            route,
+            suggestions: OnceCell::new(),
+        }
+    }
+
+    /// shortName that matches no supported chain.
+    pub(crate) fn short_name(name: &str) -> Self {
+        Self::new(
+            UnsupportedInput::Text(name.to_string()),
+            ParseRoute::ShortName,
+        )
+    }
+
+    /// Text that parsed to the chain ID `id`, which isn't supported.
+    fn parsed(input: &str, id: u64, route: ParseRoute) -> Self {
+        Self {
+            chain_id: Some(id),
+            ..Self::new(UnsupportedInput::Text(input.to_string()), route)
+        }
+    }
+
+    /// The rejected value.
+    pub fn input(&self) -> &UnsupportedInput {
+        &self.input
+    }
+
+    /// How the rejected value was interpreted.
+    pub fn route(&self) -> ParseRoute {
+        self.route
+    }
+
+    /// Rejected chain ID, if the input was numeric and fits in `u64`.
+    pub fn chain_id(&self) -> Option<u64> {
+        self.chain_id
+    }
+
+    /// Supported chains closest to the input, best first.
+    ///
+    /// Chain IDs are compared digit by digit (so `42162` suggests `42161`);
+    /// names go through [`search`](crate::search::search).
+    pub fn suggestions(&self) -> &[Chain] {
+        self.suggestions
+            .get_or_init(|| match (&self.input, self.chain_id) {
+                (_, Some(id)) => suggest_ids(id),
+                (UnsupportedInput::Text(text), None) => suggest_names(text),
+                _ => Vec::new(),
+            })
+    }
+}
+
+impl fmt::Display for ChainIdNotSupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} not supported", self.input)?;
+        match (&self.input, self.chain_id) {
+            (UnsupportedInput::Text(_), Some(id)) => write!(f, " (parsed as {} {id})", self.route)?,
+            (UnsupportedInput::Text(_), None) => write!(f, " (looked up as {})", self.route)?,
+            _ => {}
+        }
+        // Error messages end up in logs and serde errors; keep them cheap
+        if self.chain_id.is_some() {
+            let suggestions = self.suggestions();
+            if !suggestions.is_empty() {
+                write!(f, "; did you mean {}?", describe(suggestions))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ChainIdNotSupported {}
+
+fn suggest_ids(id: u64) -> Vec<Chain> {
+    let digits = id.to_string();
+    let mut candidates = Chain::iter()
+        .filter_map(|chain| {
+            let distance = levenshtein(&digits, &chain.id().to_string());
+            (distance <= 1).then_some((distance, chain.id().abs_diff(id), chain))
+        })
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|(distance, diff, _)| (*distance, *diff));
+    candidates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, _, chain)| chain)
+        .collect()
+}
+
+fn suggest_names(name: &str) -> Vec<Chain> {
+    let options = SearchOptions {
+        limit: MAX_SUGGESTIONS,
+        min_score: 0.6,
+        ..SearchOptions::default()
+    };
+    search_with(name, &options)
+        .into_iter()
+        .map(|hit| hit.chain)
+        .collect()
+}
+
+/// Errors when parsing a [`Chain`] from text.
+#[derive(Debug, Error)]
+pub enum ParseChainError {
+    #[error("empty chain identifier")]
+    Empty,
+    #[error("invalid chain id {input:?}: {source}")]
+    InvalidId {
+        input: String,
+        #[source]
+        source: ParseIntError,
+    },
+    #[error("unsupported CAIP-2 namespace {0:?}, expected \"eip155\"")]
+    UnsupportedNamespace(String),
+    #[error(transparent)]
+    NotSupported(#[from] ChainIdNotSupported),
+    #[error("ambiguous chain {input:?}: matches {}", describe(candidates))]
+    Ambiguous {
+        input: String,
+        candidates: Vec<Chain>,
+    },
+}
+
+fn describe(chains: &[Chain]) -> String {
+    chains
+        .iter()
+        .map(|chain| format!("{} ({})", chain.short_name(), chain.id()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Lowercased shortName -> chains.
+static SHORT_NAMES: Lazy<HashMap<String, Vec<Chain>>> =
+    Lazy::new(|| index_by(|chain| chain.short_name()));
+
+/// Lowercased chain family slug -> chains.
+static SLUGS: Lazy<HashMap<String, Vec<Chain>>> =
+    Lazy::new(|| index_by(|chain| chain.chain_family()));
+
+fn index_by(key: impl Fn(&Chain) -> &'static str) -> HashMap<String, Vec<Chain>> {
+    let mut index: HashMap<String, Vec<Chain>> = HashMap::new();
+    for chain in Chain::iter() {
+        index
+            .entry(key(&chain).to_ascii_lowercase())
+            .or_default()
+            .push(chain);
+    }
+    index
+}
+
+/// Chains whose shortName matches `name` case-insensitively.
+pub(crate) fn short_name_candidates(name: &str) -> &'static [Chain] {
+    SHORT_NAMES
+        .get(&name.to_ascii_lowercase())
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Picks a single chain out of `candidates`, preferring an exact-case match of `name`.
+fn resolve_candidates(
+    name: &str,
+    candidates: &[Chain],
+    key: impl Fn(&Chain) -> &'static str,
+) -> Result<Chain, ParseChainError> {
+    match candidates {
+        [] => Err(ChainIdNotSupported::new(
+            UnsupportedInput::Text(name.to_string()),
+            ParseRoute::Name,
+        )
+        .into()),
+        [chain] => Ok(*chain),
+        _ => {
+            let exact = candidates
+                .iter()
+                .filter(|chain| key(chain) == name)
+                .collect::<Vec<_>>();
+            match exact.as_slice() {
+                [chain] => Ok(**chain),
+                _ => Err(ParseChainError::Ambiguous {
+                    input: name.to_string(),
+                    candidates: candidates.to_vec(),
+                }),
+            }
+        }
+    }
+}
+
+fn parse_id(
+    input: &str,
+    digits: &str,
+    radix: u32,
+    route: ParseRoute,
+) -> Result<Chain, ParseChainError> {
+    let id = parse_digits(digits, radix).map_err(|source| ParseChainError::InvalidId {
+        input: input.to_string(),
+        source,
+    })?;
+    Chain::try_from(id).map_err(|_| ChainIdNotSupported::parsed(input, id, route).into())
+}
+
+/// `from_str_radix` without its leading `+`, which chain ids never carry.
+fn parse_digits(digits: &str, radix: u32) -> Result<u64, ParseIntError> {
+    if digits.starts_with('+') {
+        // A lone sign is std's own invalid-digit error
+        return u64::from_str_radix("+", radix);
+    }
+    u64::from_str_radix(digits, radix)
+}
+
+impl Chain {
+    /// Looks up a chain by its shortName (case-insensitive, exact case wins ties).
+    pub fn from_short_name(name: &str) -> Option<Chain> {
+        resolve_candidates(name, short_name_candidates(name), Chain::short_name).ok()
+    }
+
+    /// CAIP-2 identifier of the chain, e.g. `eip155:1`.
+    pub fn caip2(&self) -> String {
+        format!("{EIP155_NAMESPACE}:{}", self.id())
+    }
+}
+
+impl FromStr for Chain {
+    type Err = ParseChainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+        if input.is_empty() {
+            return Err(ParseChainError::Empty);
+        }
+
+        // CAIP-2: `eip155:<decimal id>`
+        if let Some((namespace, reference)) = input.split_once(':') {
+            // Namespaces are case-sensitive, as in `caip::ChainId`
+            if namespace != EIP155_NAMESPACE {
+                return Err(ParseChainError::UnsupportedNamespace(namespace.to_string()));
+            }
+            return parse_id(input, reference, 10, ParseRoute::Caip2);
+        }
+
+        if let Some(hex) = input
+            .strip_prefix("0x")
+            .or_else(|| input.strip_prefix("0X"))
+        {
+            match parse_id(input, hex, 16, ParseRoute::Hex) {
+                // A few shortNames start with `0x`, e.g. `0xl3`
+                Err(ParseChainError::InvalidId { .. })
+                    if !short_name_candidates(input).is_empty() => {}
+                result => return result,
+            }
+        }
+
+        if input.bytes().all(|b| b.is_ascii_digit()) {
+            return parse_id(input, input, 10, ParseRoute::Decimal);
+        }
+
+        let short_names = short_name_candidates(input);
+        if !short_names.is_empty() {
+            return resolve_candidates(input, short_names, Chain::short_name);
+        }
+
+        let slugs = SLUGS
+            .get(&input.to_ascii_lowercase())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        resolve_candidates(input, slugs, Chain::chain_family)
+    }
+}
@@ -0,0 +1,93 @@
+//! Provenance of the bundled chain data.
+//!
+//! The build script records where `chains.json` came from, when it was
+//! retrieved and its SHA-256, so binaries can report the data they were built
+//! with (e.g. at startup or on a health endpoint).
+
+use serde::Serialize;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How the build script obtained `chains.json`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataSourceKind {
+    /// Local file supplied through `CHAINS_JSON_PATH`.
+    Override,
+    /// Local snapshot used without attempting a download.
+    Snapshot,
+    /// Previously downloaded copy, reused within the TTL or after a failed download.
+    Cache,
+    /// Downloaded during this build.
+    Download,
+}
+
+impl DataSourceKind {
+    /// Lowercase name of the source kind.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Override => "override",
+            Self::Snapshot => "snapshot",
+            Self::Cache => "cache",
+            Self::Download => "download",
+        }
+    }
+}
+
+impl fmt::Display for DataSourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Where the bundled chain data came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataProvenance {
+    /// URL or filesystem path `chains.json` was read from. Paths inside the
+    /// crate are relative to its root (e.g. `data/chains.json`).
+    pub source: &'static str,
+    /// How the data was obtained.
+    pub kind: DataSourceKind,
+    /// Unix timestamp (seconds) of the download. For local files (snapshot or
+    /// `CHAINS_JSON_PATH`) this is `SOURCE_DATE_EPOCH` if it was set at build
+    /// time, and `None` otherwise.
+    pub retrieved_at: Option<u64>,
+    /// SHA-256 (lowercase hex) of the source `chains.json`.
+    pub sha256: &'static str,
+    /// Number of chain records bundled into the crate (after overlays and selection).
+    pub record_count: usize,
+    /// Overlay files merged on top of the source, in order.
+    pub overlays: &'static [&'static str],
+}
+
+impl DataProvenance {
+    /// Retrieval time as a [`SystemTime`], if known.
+    pub fn retrieved_at_time(&self) -> Option<SystemTime> {
+        self.retrieved_at
+            .map(|secs| UNIX_EPOCH + Duration::from_secs(secs))
+    }
+}
+
+impl fmt::Display for DataProvenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} chains from {} ({}",
+            self.record_count, self.source, self.kind
+        )?;
+        if let Some(retrieved_at) = self.retrieved_at {
+            write!(f, ", retrieved at {retrieved_at}")?;
+        }
+        write!(f, "), sha256 {}", self.sha256)?;
+        if !self.overlays.is_empty() {
+            write!(f, ", overlays: {}", self.overlays.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Provenance of the chain data this crate was built with.
+pub fn data_provenance() -> &'static DataProvenance {
+    &crate::DATA_PROVENANCE
+}
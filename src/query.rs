@@ -0,0 +1,231 @@
+//! Declarative chain filters.
+//!
+//! ```rust
+//! use chainlist_rs::{Chain, ChainFeature, ChainQuery, ChainStatus};
+//!
+//! let l2s = ChainQuery::new()
+//!     .feature(ChainFeature::Eip1559)
+//!     .parent(Chain::Mainnet)
+//!     .status(ChainStatus::Active)
+//!     .has_explorer("EIP3091");
+//!
+//! for chain in l2s.chains() {
+//!     println!("{} ({})", chain.name(), chain.id());
+//! }
+//! ```
+
+use crate::feature::ChainFeature;
+use crate::schema::ChainRecord;
+use crate::status::ChainStatus;
+use crate::{all_chains, Chain};
+use strum::IntoEnumIterator;
+
+/// Composable filter over [`Chain`] and [`ChainRecord`].
+///
+/// Every condition must hold; an empty query matches everything.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChainQuery {
+    features: Vec<ChainFeature>,
+    parent: Option<u64>,
+    status: Option<ChainStatus>,
+    explorer_standards: Vec<String>,
+    chain_family: Option<String>,
+    testnet: Option<bool>,
+    safe_to_add: Option<bool>,
+}
+
+impl ChainQuery {
+    /// Query matching every chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires the chain to advertise `feature`. Can be repeated.
+    pub fn feature(mut self, feature: ChainFeature) -> Self {
+        self.features.push(feature);
+        self
+    }
+
+    /// Requires the chain's parent (e.g. the L1 of an L2) to be `parent`.
+    pub fn parent(self, parent: Chain) -> Self {
+        self.parent_id(parent.id())
+    }
+
+    /// Requires the chain's parent to have chain ID `parent_id`.
+    ///
+    /// Useful when the parent isn't part of the generated [`Chain`] enum.
+    pub fn parent_id(mut self, parent_id: u64) -> Self {
+        self.parent = Some(parent_id);
+        self
+    }
+
+    /// Requires the chain to have the given lifecycle status.
+    pub fn status(mut self, status: ChainStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Requires an explorer implementing `standard` (e.g. "EIP3091",
+    /// case-insensitive). Can be repeated.
+    pub fn has_explorer(mut self, standard: impl Into<String>) -> Self {
+        self.explorer_standards.push(standard.into());
+        self
+    }
+
+    /// Requires the chain family (the `chain` field, e.g. "ETH") to match,
+    /// case-insensitive.
+    pub fn chain_family(mut self, family: impl Into<String>) -> Self {
+        self.chain_family = Some(family.into());
+        self
+    }
+
+    /// Keeps only test networks (`true`) or only non-test networks (`false`).
+    pub fn testnet(mut self, testnet: bool) -> Self {
+        self.testnet = Some(testnet);
+        self
+    }
+
+    /// Keeps only chains that pass (`true`) or fail (`false`) the
+    /// wallet-safety policy of [`Chain::is_safe_to_add`].
+    pub fn safe_to_add(mut self, safe: bool) -> Self {
+        self.safe_to_add = Some(safe);
+        self
+    }
+
+    /// Whether `item` satisfies every condition.
+    pub fn matches<T: Queryable + ?Sized>(&self, item: &T) -> bool {
+        self.features
+            .iter()
+            .all(|feature| item.supports_feature(feature))
+            && self
+                .parent
+                .is_none_or(|parent| item.parent_chain_id() == Some(parent))
+            && self
+                .status
+                .as_ref()
+                .is_none_or(|status| item.chain_status() == *status)
+            && self
+                .explorer_standards
+                .iter()
+                .all(|standard| item.has_explorer_standard(standard))
+            && self
+                .chain_family
+                .as_deref()
+                .is_none_or(|family| item.family().eq_ignore_ascii_case(family))
+            && self
+                .testnet
+                .is_none_or(|testnet| item.looks_like_testnet() == testnet)
+            && self
+                .safe_to_add
+                .is_none_or(|safe| item.passes_safety_policy() == safe)
+    }
+
+    /// Generated chains matching the query, in chain ID order.
+    pub fn chains(&self) -> impl Iterator<Item = Chain> + '_ {
+        Chain::iter().filter(move |chain| self.matches(chain))
+    }
+
+    /// Bundled records matching the query, in `chains.json` order.
+    pub fn records(&self) -> impl Iterator<Item = &'static ChainRecord> + '_ {
+        all_chains()
+            .iter()
+            .filter(move |record| self.matches(*record))
+    }
+
+    /// Filters any iterator of chains or records.
+    pub fn filter<'a, T, I>(&'a self, items: I) -> impl Iterator<Item = I::Item> + 'a
+    where
+        T: Queryable + ?Sized + 'a,
+        I: IntoIterator<Item = &'a T>,
+        I::IntoIter: 'a,
+    {
+        items.into_iter().filter(move |item| self.matches(*item))
+    }
+}
+
+/// Types a [`ChainQuery`] can be evaluated against: [`Chain`] and [`ChainRecord`].
+pub trait Queryable: private::Sealed {}
+
+impl Queryable for Chain {}
+impl Queryable for ChainRecord {}
+
+mod private {
+    use crate::feature::ChainFeature;
+    use crate::schema::ChainRecord;
+    use crate::status::ChainStatus;
+    use crate::Chain;
+
+    pub trait Sealed {
+        fn supports_feature(&self, feature: &ChainFeature) -> bool;
+        fn parent_chain_id(&self) -> Option<u64>;
+        fn chain_status(&self) -> ChainStatus;
+        fn has_explorer_standard(&self, standard: &str) -> bool;
+        fn family(&self) -> &str;
+        fn looks_like_testnet(&self) -> bool;
+        fn passes_safety_policy(&self) -> bool;
+    }
+
+    impl Sealed for Chain {
+        fn supports_feature(&self, feature: &ChainFeature) -> bool {
+            self.features().iter().any(|name| feature.matches(name))
+        }
+
+        fn parent_chain_id(&self) -> Option<u64> {
+            self.parent()?.chain_id()
+        }
+
+        fn chain_status(&self) -> ChainStatus {
+            self.status()
+        }
+
+        fn has_explorer_standard(&self, standard: &str) -> bool {
+            self.explorers()
+                .iter()
+                .any(|explorer| explorer.standard.eq_ignore_ascii_case(standard))
+        }
+
+        fn family(&self) -> &str {
+            self.chain_family()
+        }
+
+        fn looks_like_testnet(&self) -> bool {
+            self.is_testnet()
+        }
+
+        fn passes_safety_policy(&self) -> bool {
+            self.is_safe_to_add()
+        }
+    }
+
+    impl Sealed for ChainRecord {
+        fn supports_feature(&self, feature: &ChainFeature) -> bool {
+            self.features.iter().any(|f| feature.matches(&f.name))
+        }
+
+        fn parent_chain_id(&self) -> Option<u64> {
+            self.parent()?.chain_id()
+        }
+
+        fn chain_status(&self) -> ChainStatus {
+            self.status()
+        }
+
+        fn has_explorer_standard(&self, standard: &str) -> bool {
+            self.explorers
+                .iter()
+                .any(|explorer| explorer.standard.eq_ignore_ascii_case(standard))
+        }
+
+        fn family(&self) -> &str {
+            &self.chain
+        }
+
+        fn looks_like_testnet(&self) -> bool {
+            self.is_testnet()
+        }
+
+        fn passes_safety_policy(&self) -> bool {
+            self.is_safe_to_add()
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Identify chains from explorer and RPC URLs.
+//!
+//! ```rust
+//! use chainlist_rs::resolve::resolve_explorer_url;
+//! use chainlist_rs::{Chain, ExplorerTarget};
+//!
+//! let (chain, target) = resolve_explorer_url("https://etherscan.io/block/17000000").unwrap();
+//! assert_eq!(chain, Chain::Mainnet);
+//! assert_eq!(target, ExplorerTarget::Block(17_000_000));
+//! ```
+//!
+//! URLs are matched on host and the longest base path among the
+//! `explorers[].url` and `rpc` entries of [`all_chains()`]; the scheme, a leading
+//! `www.`, query strings and fragments are ignored. When one host is
+//! listed by several chains, the most likely one is picked (wallet-safe before
+//! unsafe, mainnets before testnets, then lowest chain ID) and the rest are
+//! reported as [`UrlMatch::alternatives`].
+
+use crate::explorer::ExplorerTarget;
+use crate::{all_chains, Chain};
+use alloy_primitives::{Address, B256};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Errors when resolving a URL to a chain.
+#[derive(Debug, Error)]
+pub enum ResolveUrlError {
+    #[error("invalid URL {0:?}")]
+    InvalidUrl(String),
+    #[error("no chain explorer or RPC endpoint matches {0:?}")]
+    Unknown(String),
+    #[error("{0:?} does not point at a transaction, address, block or token page")]
+    NoTarget(String),
+}
+
+/// What kind of endpoint a URL belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UrlKind {
+    Explorer,
+    Rpc,
+}
+
+/// Chain identified from a URL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UrlMatch {
+    pub kind: UrlKind,
+    /// Most likely chain.
+    pub chain: Chain,
+    /// Explorer page the URL points at, if any.
+    pub target: Option<ExplorerTarget>,
+    /// Other chains listing the same endpoint, most likely first.
+    pub alternatives: Vec<Chain>,
+}
+
+impl UrlMatch {
+    /// Whether several chains list the endpoint.
+    pub fn is_ambiguous(&self) -> bool {
+        !self.alternatives.is_empty()
+    }
+}
+
+/// Host -> (base path segments, chain).
+type UrlIndex = HashMap<String, Vec<(Vec<String>, Chain)>>;
+
+static EXPLORERS: Lazy<UrlIndex> =
+    Lazy::new(|| build_index(|record| record.explorers.iter().map(|e| e.url.as_str()).collect()));
+
+static RPCS: Lazy<UrlIndex> =
+    Lazy::new(|| build_index(|record| record.rpc.iter().map(String::as_str).collect()));
+
+fn build_index(urls: impl Fn(&crate::schema::ChainRecord) -> Vec<&str>) -> UrlIndex {
+    let mut index = UrlIndex::new();
+    for record in all_chains() {
+        let Ok(chain) = Chain::try_from(record) else {
+            continue;
+        };
+        for url in urls(record) {
+            let Some((host, path)) = split_url(url) else {
+                continue;
+            };
+            // Stop at templated segments such as `${INFURA_API_KEY}`
+            let base = path
+                .into_iter()
+                .take_while(|segment| !segment.contains('{'))
+                .map(|segment| segment.to_ascii_lowercase())
+                .collect::<Vec<_>>();
+            let entries = index.entry(host).or_default();
+            if !entries.contains(&(base.clone(), chain)) {
+                entries.push((base, chain));
+            }
+        }
+    }
+    index
+}
+
+/// Normalized host of a URL (lowercase, without `www.` or a default port).
+pub(crate) fn url_host(url: &str) -> Option<String> {
+    split_url(url).map(|(host, _)| host)
+}
+
+/// Chains listing `url` as an endpoint of `kind`, most likely first.
+pub(crate) fn listed_by(kind: UrlKind, url: &str) -> Vec<Chain> {
+    let index = match kind {
+        UrlKind::Explorer => &EXPLORERS,
+        UrlKind::Rpc => &RPCS,
+    };
+    split_url(url)
+        .and_then(|(host, path)| lookup(index, &host, &path))
+        .map(|(chains, _)| chains)
+        .unwrap_or_default()
+}
+
+/// Splits a URL into its normalized host and path segments.
+fn split_url(url: &str) -> Option<(String, Vec<&str>)> {
+    let url = url.trim();
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    let host = host.to_ascii_lowercase();
+    // Default ports name the same endpoint; others (e.g. `:8545`) are kept
+    let host = [":443", ":80"]
+        .iter()
+        .find_map(|port| host.strip_suffix(port))
+        .unwrap_or(&host);
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    if host.is_empty() || host.contains(char::is_whitespace) {
+        return None;
+    }
+    let segments = path.split('/').filter(|s| !s.is_empty()).collect();
+    Some((host.to_string(), segments))
+}
+
+/// Finds the chains whose entry for `host` is the longest prefix of `path`.
+/// Returns them most likely first, with the number of path segments consumed.
+fn lookup(index: &UrlIndex, host: &str, path: &[&str]) -> Option<(Vec<Chain>, usize)> {
+    let entries = index.get(host)?;
+    let matching = entries.iter().filter(|(base, _)| {
+        base.len() <= path.len()
+            && base
+                .iter()
+                .zip(path)
+                .all(|(b, p)| b.eq_ignore_ascii_case(p))
+    });
+    let best = matching.clone().map(|(base, _)| base.len()).max()?;
+    let mut chains = matching
+        .filter(|(base, _)| base.len() == best)
+        .map(|(_, chain)| *chain)
+        .collect::<Vec<_>>();
+    chains.sort_by_key(|chain| (!chain.is_safe_to_add(), chain.is_testnet(), chain.id()));
+    chains.dedup();
+    Some((chains, best))
+}
+
+/// Parses the EIP-3091 page in the path after an explorer's base URL.
+fn parse_target(path: &[&str]) -> Option<ExplorerTarget> {
+    let [kind, value, ..] = path else {
+        return None;
+    };
+    match kind.to_ascii_lowercase().as_str() {
+        "tx" => value.parse::<B256>().ok().map(ExplorerTarget::Tx),
+        "address" => value.parse::<Address>().ok().map(ExplorerTarget::Address),
+        "token" => value.parse::<Address>().ok().map(ExplorerTarget::Token),
+        "block" => value.parse::<u64>().ok().map(ExplorerTarget::Block),
+        _ => None,
+    }
+}
+
+/// Identifies the chain of an explorer or RPC URL. Explorers are tried first.
+pub fn resolve_url(url: &str) -> Result<UrlMatch, ResolveUrlError> {
+    let (host, path) =
+        split_url(url).ok_or_else(|| ResolveUrlError::InvalidUrl(url.to_string()))?;
+
+    let (kind, chains, consumed) =
+        if let Some((chains, consumed)) = lookup(&EXPLORERS, &host, &path) {
+            (UrlKind::Explorer, chains, consumed)
+        } else if let Some((chains, consumed)) = lookup(&RPCS, &host, &path) {
+            (UrlKind::Rpc, chains, consumed)
+        } else {
+            return Err(ResolveUrlError::Unknown(url.to_string()));
+        };
+
+    let target = match kind {
+        UrlKind::Explorer => parse_target(&path[consumed..]),
+        UrlKind::Rpc => None,
+    };
+    let mut chains = chains.into_iter();
+    let chain = chains
+        .next()
+        .ok_or_else(|| ResolveUrlError::Unknown(url.to_string()))?;
+    Ok(UrlMatch {
+        kind,
+        chain,
+        target,
+        alternatives: chains.collect(),
+    })
+}
+
+/// Identifies the chain and page of an EIP-3091 explorer link.
+///
+/// Picks the most likely chain when the explorer is shared; use
+/// [`resolve_url`] to see the alternatives.
+pub fn resolve_explorer_url(url: &str) -> Result<(Chain, ExplorerTarget), ResolveUrlError> {
+    let (host, path) =
+        split_url(url).ok_or_else(|| ResolveUrlError::InvalidUrl(url.to_string()))?;
+    let (chains, consumed) = lookup(&EXPLORERS, &host, &path)
+        .ok_or_else(|| ResolveUrlError::Unknown(url.to_string()))?;
+    let target = parse_target(&path[consumed..])
+        .ok_or_else(|| ResolveUrlError::NoTarget(url.to_string()))?;
+    let chain = *chains
+        .first()
+        .ok_or_else(|| ResolveUrlError::Unknown(url.to_string()))?;
+    Ok((chain, target))
+}
+
+/// Chains listing the RPC endpoint, most likely first.
+pub fn resolve_rpc_url(url: &str) -> Result<Vec<Chain>, ResolveUrlError> {
+    let (host, path) =
+        split_url(url).ok_or_else(|| ResolveUrlError::InvalidUrl(url.to_string()))?;
+    lookup(&RPCS, &host, &path)
+        .map(|(chains, _)| chains)
+        .ok_or_else(|| ResolveUrlError::Unknown(url.to_string()))
+}
@@ -126,6 +126,11 @@ impl Parent {
     pub fn bridges(&self) -> &[Bridge] {
         &self.bridges
     }
+
+    /// Parent chain ID, if the reference has the `eip155-<id>` form.
+    pub fn chain_id(&self) -> Option<u64> {
+        self.chain.strip_prefix("eip155-")?.parse().ok()
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -0,0 +1,259 @@
+//! Fuzzy chain search.
+//!
+//! Scores every chain's name, title, shortName, chain family and native
+//! currency symbol against a free-text query (e.g. "arbitrum nova",
+//! "polygon zk") and returns ranked matches.
+
+use crate::schema::ChainRecord;
+use crate::status::ChainStatus;
+use crate::Chain;
+use std::cmp::Ordering;
+use strum::IntoEnumIterator;
+
+/// SLIP-44 coin type reserved for "Testnet (all coins)".
+const TESTNET_COIN_TYPE: u64 = 1;
+
+/// Name fragments that mark a test network.
+const TESTNET_MARKERS: &[&str] = &[
+    "testnet", "test", "devnet", "sepolia", "goerli", "holesky", "hoodi", "ropsten", "rinkeby",
+    "kovan", "mumbai", "amoy", "fuji", "chiado",
+];
+
+/// Chain field that produced a match.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatchField {
+    Name,
+    Title,
+    ShortName,
+    ChainFamily,
+    CurrencySymbol,
+}
+
+/// A ranked search result.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SearchHit {
+    pub chain: Chain,
+    /// Relevance in `0.0..=1.0`; higher is better.
+    pub score: f64,
+    /// Field that scored best.
+    pub field: MatchField,
+}
+
+/// Search tuning and filters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchOptions {
+    /// Maximum number of hits returned.
+    pub limit: usize,
+    /// Hits scoring below this are dropped.
+    pub min_score: f64,
+    /// Only return chains with this status.
+    pub status: Option<ChainStatus>,
+    /// Only return test networks (`Some(true)`) or non-test networks (`Some(false)`).
+    pub testnet: Option<bool>,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            limit: 10,
+            min_score: 0.5,
+            status: None,
+            testnet: None,
+        }
+    }
+}
+
+/// Searches all chains with the default [`SearchOptions`].
+pub fn search(query: &str) -> Vec<SearchHit> {
+    search_with(query, &SearchOptions::default())
+}
+
+/// Searches all chains, best matches first.
+///
+/// Ties are broken by chain ID so results are deterministic.
+pub fn search_with(query: &str, options: &SearchOptions) -> Vec<SearchHit> {
+    let query = normalize(query);
+    if query.is_empty() || options.limit == 0 {
+        return Vec::new();
+    }
+
+    let mut hits = Chain::iter()
+        .filter(|chain| {
+            options
+                .status
+                .as_ref()
+                .is_none_or(|status| chain.status() == *status)
+        })
+        .filter(|chain| {
+            options
+                .testnet
+                .is_none_or(|testnet| chain.is_testnet() == testnet)
+        })
+        .filter_map(|chain| score_chain(&query, chain))
+        .filter(|hit| hit.score >= options.min_score)
+        .collect::<Vec<_>>();
+
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.chain.id().cmp(&b.chain.id()))
+    });
+    hits.truncate(options.limit);
+    hits
+}
+
+fn score_chain(query: &str, chain: Chain) -> Option<SearchHit> {
+    let info = chain.info();
+    let fields = [
+        (MatchField::Name, Some(info.name), 1.0),
+        (MatchField::Title, info.title, 0.95),
+        (MatchField::ShortName, Some(info.short_name), 1.0),
+        (MatchField::ChainFamily, Some(info.chain), 0.7),
+        (
+            MatchField::CurrencySymbol,
+            Some(info.native_currency.symbol),
+            0.6,
+        ),
+    ];
+
+    fields
+        .into_iter()
+        .filter_map(|(field, value, weight)| {
+            let score = score_text(query, &normalize(value?)) * weight;
+            (score > 0.0).then_some(SearchHit {
+                chain,
+                score,
+                field,
+            })
+        })
+        .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(Ordering::Equal))
+}
+
+/// Scores a normalized query against a normalized field value.
+fn score_text(query: &str, value: &str) -> f64 {
+    if value.is_empty() {
+        return 0.0;
+    }
+    if query == value {
+        return 1.0;
+    }
+
+    // Prefer values that don't carry much beyond the query
+    let coverage = query.len().min(value.len()) as f64 / query.len().max(value.len()) as f64;
+
+    if value.starts_with(query) {
+        return 0.85 + 0.1 * coverage;
+    }
+
+    let query_tokens = query.split(' ').collect::<Vec<_>>();
+    let value_tokens = value.split(' ').collect::<Vec<_>>();
+    if query_tokens
+        .iter()
+        .all(|q| value_tokens.iter().any(|v| v.starts_with(q)))
+    {
+        return 0.75 + 0.1 * coverage;
+    }
+
+    if value.contains(query) {
+        return 0.65 + 0.1 * coverage;
+    }
+
+    // Edit-distance similarity, token by token, to absorb typos
+    let token_similarity = query_tokens
+        .iter()
+        .map(|q| {
+            value_tokens
+                .iter()
+                .map(|v| similarity(q, v))
+                .fold(0.0, f64::max)
+        })
+        .sum::<f64>()
+        / query_tokens.len() as f64;
+    let whole_similarity = similarity(query, value);
+
+    0.7 * token_similarity.max(whole_similarity)
+}
+
+/// `1 - levenshtein / max_len`, in `0.0..=1.0`.
+fn similarity(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f64 / max_len as f64
+}
+
+pub(crate) fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Lowercases and collapses everything that isn't alphanumeric into single spaces.
+pub(crate) fn normalize(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Registry data first: the testnet coin type marks a testnet unless the chain
+/// is explicitly named a mainnet. Other coin types prove nothing, since many
+/// testnets reuse their mainnet's. Otherwise the names decide, with a
+/// "mainnet" token overriding any marker.
+fn is_testnet(slip44: Option<u64>, names: &[Option<&str>]) -> bool {
+    let names = names
+        .iter()
+        .flatten()
+        .map(|name| normalize(name))
+        .collect::<Vec<_>>();
+    let tokens = names
+        .iter()
+        .flat_map(|name| name.split(' '))
+        .collect::<Vec<_>>();
+    if tokens.contains(&"mainnet") {
+        return false;
+    }
+    slip44 == Some(TESTNET_COIN_TYPE) || tokens.iter().any(|t| TESTNET_MARKERS.contains(t))
+}
+
+impl Chain {
+    /// Whether the chain is a test network: SLIP-44 coin type 1 in the
+    /// registry, or failing that a name, title or shortName such as
+    /// "Sepolia", "Testnet" or "Devnet". Chains named "Mainnet" never are.
+    pub fn is_testnet(&self) -> bool {
+        is_testnet(
+            self.slip44().and_then(|coin| u64::try_from(coin).ok()),
+            &[Some(self.name()), self.title(), Some(self.short_name())],
+        )
+    }
+}
+
+impl ChainRecord {
+    /// Whether the chain is a test network: SLIP-44 coin type 1 in the
+    /// registry, or failing that a name, title or shortName such as
+    /// "Sepolia", "Testnet" or "Devnet". Chains named "Mainnet" never are.
+    pub fn is_testnet(&self) -> bool {
+        is_testnet(
+            self.slip44,
+            &[
+                Some(&self.name),
+                self.title.as_deref(),
+                Some(&self.short_name),
+            ],
+        )
+    }
+}
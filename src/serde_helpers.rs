@@ -0,0 +1,82 @@
+//! Alternative serde representations for [`Chain`].
+//!
+//! [`Chain`] serializes as its decimal chain ID by default. Use these modules
+//! with `#[serde(with = "...")]` to store it another way:
+//!
+//! ```rust
+//! use chainlist_rs::Chain;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "chainlist_rs::serde_helpers::short_name")]
+//!     chain: Chain,
+//!     #[serde(with = "chainlist_rs::serde_helpers::hex")]
+//!     settlement: Chain,
+//! }
+//!
+//! let json = r#"{"chain":"base","settlement":"0x1"}"#;
+//! let config: Config = serde_json::from_str(json).unwrap();
+//! assert_eq!(config.settlement, Chain::Mainnet);
+//! assert_eq!(serde_json::to_string(&config).unwrap(), json);
+//! ```
+//!
+//! Deserializing is lenient in every module: any form accepted by
+//! [`Chain`]'s own `Deserialize` (numeric ID, decimal, hex or CAIP-2 string,
+//! shortName) is accepted, so configs can switch representation without
+//! breaking existing files.
+
+use crate::Chain;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Decimal chain ID, e.g. `8453`. Same as the default representation.
+pub mod decimal {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(chain: &Chain, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(chain.id())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Chain, D::Error> {
+        Chain::deserialize(deserializer)
+    }
+}
+
+/// `0x`-prefixed hex chain ID string, e.g. `"0x2105"` (as used by EIP-695 and EIP-3085).
+pub mod hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(chain: &Chain, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:#x}", chain.id()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Chain, D::Error> {
+        Chain::deserialize(deserializer)
+    }
+}
+
+/// shortName string, e.g. `"base"`.
+pub mod short_name {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(chain: &Chain, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(chain.short_name())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Chain, D::Error> {
+        Chain::deserialize(deserializer)
+    }
+}
+
+/// CAIP-2 string, e.g. `"eip155:8453"`.
+pub mod caip2 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(chain: &Chain, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&chain.caip2())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Chain, D::Error> {
+        Chain::deserialize(deserializer)
+    }
+}
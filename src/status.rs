@@ -0,0 +1,171 @@
+//! Chain lifecycle status, red flags and the wallet-safety policy.
+
+use crate::schema::ChainRecord;
+use crate::Chain;
+use std::fmt;
+use thiserror::Error;
+
+/// Lifecycle status of a chain. Chains without a status are [`ChainStatus::Active`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChainStatus {
+    Active,
+    Deprecated,
+    Incubating,
+    /// Any other status value, kept verbatim.
+    Other(String),
+}
+
+impl ChainStatus {
+    /// Status value as it appears in `chains.json`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Active => "active",
+            Self::Deprecated => "deprecated",
+            Self::Incubating => "incubating",
+            Self::Other(status) => status,
+        }
+    }
+
+    /// Parses an optional raw status, defaulting to [`ChainStatus::Active`].
+    pub fn from_raw(status: Option<&str>) -> Self {
+        status.map(Self::from).unwrap_or(Self::Active)
+    }
+}
+
+impl From<&str> for ChainStatus {
+    fn from(status: &str) -> Self {
+        let status = status.trim();
+        if status.eq_ignore_ascii_case("active") {
+            Self::Active
+        } else if status.eq_ignore_ascii_case("deprecated") {
+            Self::Deprecated
+        } else if status.eq_ignore_ascii_case("incubating") {
+            Self::Incubating
+        } else {
+            Self::Other(status.to_string())
+        }
+    }
+}
+
+impl fmt::Display for ChainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Warning attached to a chain by the registry maintainers.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RedFlag {
+    /// The chain ID is used by more than one network.
+    ReusedChainId,
+    /// Any other red flag, kept verbatim.
+    Other(String),
+}
+
+impl RedFlag {
+    /// Red flag as it appears in `chains.json`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ReusedChainId => "reusedChainId",
+            Self::Other(flag) => flag,
+        }
+    }
+}
+
+impl From<&str> for RedFlag {
+    fn from(flag: &str) -> Self {
+        let flag = flag.trim();
+        if flag.eq_ignore_ascii_case("reusedChainId") {
+            Self::ReusedChainId
+        } else {
+            Self::Other(flag.to_string())
+        }
+    }
+}
+
+impl fmt::Display for RedFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Why a chain should not be offered through `wallet_addEthereumChain`.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum UnsafeChain {
+    #[error("chain {0} is deprecated")]
+    Deprecated(u64),
+    #[error("chain {chain_id} is red-flagged: {}", flags.iter().map(RedFlag::as_str).collect::<Vec<_>>().join(", "))]
+    RedFlagged { chain_id: u64, flags: Vec<RedFlag> },
+}
+
+/// Wallet policy: deprecated and red-flagged chains must not be offered.
+fn check_safe_to_add(
+    chain_id: u64,
+    status: ChainStatus,
+    flags: Vec<RedFlag>,
+) -> Result<(), UnsafeChain> {
+    if !flags.is_empty() {
+        return Err(UnsafeChain::RedFlagged { chain_id, flags });
+    }
+    if status == ChainStatus::Deprecated {
+        return Err(UnsafeChain::Deprecated(chain_id));
+    }
+    Ok(())
+}
+
+impl Chain {
+    /// Lifecycle status of the chain.
+    pub fn status(&self) -> ChainStatus {
+        ChainStatus::from_raw(self.info().status)
+    }
+
+    /// Raw red flags of the chain.
+    pub fn red_flags(&self) -> &'static [&'static str] {
+        self.info().red_flags
+    }
+
+    /// Typed red flags of the chain.
+    pub fn red_flag_kinds(&self) -> Vec<RedFlag> {
+        self.red_flags()
+            .iter()
+            .map(|flag| RedFlag::from(*flag))
+            .collect()
+    }
+
+    /// Checks the chain against the wallet-safety policy.
+    pub fn check_safe_to_add(&self) -> Result<(), UnsafeChain> {
+        check_safe_to_add(self.id(), self.status(), self.red_flag_kinds())
+    }
+
+    /// Whether the chain may be offered through `wallet_addEthereumChain`:
+    /// it is not deprecated and carries no red flags.
+    pub fn is_safe_to_add(&self) -> bool {
+        self.check_safe_to_add().is_ok()
+    }
+}
+
+impl ChainRecord {
+    /// Lifecycle status of the chain.
+    pub fn status(&self) -> ChainStatus {
+        ChainStatus::from_raw(self.status.as_deref())
+    }
+
+    /// Typed red flags of the chain.
+    pub fn red_flag_kinds(&self) -> Vec<RedFlag> {
+        self.red_flags
+            .iter()
+            .map(|flag| RedFlag::from(flag.as_str()))
+            .collect()
+    }
+
+    /// Checks the chain against the wallet-safety policy.
+    pub fn check_safe_to_add(&self) -> Result<(), UnsafeChain> {
+        check_safe_to_add(self.chain_id, self.status(), self.red_flag_kinds())
+    }
+
+    /// Whether the chain may be offered through `wallet_addEthereumChain`:
+    /// it is not deprecated and carries no red flags.
+    pub fn is_safe_to_add(&self) -> bool {
+        self.check_safe_to_add().is_ok()
+    }
+}
@@ -0,0 +1,510 @@
+//! Wallet-side checks for `wallet_addEthereumChain` parameters.
+//!
+//! - [`Eip3085Params::sanitize`] turns parameters built from `chains.json`
+//!   into ones wallets such as MetaMask accept, reporting everything it
+//!   dropped, fixed or could not fix.
+//! - [`Eip3085Params::verify`] checks parameters received from a dapp against
+//!   the bundled registry and returns a [`RiskLevel`] verdict.
+
+use crate::eip::Eip3085Params;
+use crate::find_record;
+use crate::resolve::{listed_by, url_host, UrlKind};
+use crate::schema::ChainRecord;
+use crate::search::normalize;
+use crate::status::{ChainStatus, RedFlag};
+use std::fmt;
+use thiserror::Error;
+
+/// Largest chain ID MetaMask accepts: its `MAX_SAFE_CHAIN_ID`,
+/// `floor(Number.MAX_SAFE_INTEGER / 2) - 19`.
+pub const MAX_SAFE_CHAIN_ID: u64 = 4_503_599_627_370_476;
+
+/// Decimals wallets require for the native currency.
+pub const NATIVE_CURRENCY_DECIMALS: u8 = 18;
+
+/// Accepted native currency symbol lengths.
+pub const SYMBOL_LENGTH: std::ops::RangeInclusive<usize> = 2..=6;
+
+/// Why a URL was dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UrlRejection {
+    /// Not an `https://` URL (e.g. `http://`, `wss://`).
+    NotHttps,
+    /// Contains a placeholder such as `${INFURA_API_KEY}`.
+    Template,
+    /// No host, or contains whitespace.
+    Malformed,
+    /// Listed more than once.
+    Duplicate,
+}
+
+impl fmt::Display for UrlRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotHttps => "not an https URL",
+            Self::Template => "contains a template placeholder",
+            Self::Malformed => "malformed URL",
+            Self::Duplicate => "duplicate URL",
+        })
+    }
+}
+
+/// Problem found while sanitizing [`Eip3085Params`].
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum Eip3085Issue {
+    #[error("chain id {0:?} is not a 0x-prefixed hex number")]
+    InvalidChainId(String),
+    #[error("chain id 0 is not a valid chain")]
+    ZeroChainId,
+    #[error("chain id {0} exceeds the wallet limit {MAX_SAFE_CHAIN_ID}")]
+    ChainIdTooLarge(u64),
+    #[error("chain id {from:?} normalized to {to:?}")]
+    NormalizedChainId { from: String, to: String },
+    #[error("chain name is empty")]
+    EmptyChainName,
+    #[error("chain name {0:?} trimmed")]
+    TrimmedChainName(String),
+    #[error("dropped RPC URL {url:?}: {reason}")]
+    DroppedRpcUrl { url: String, reason: UrlRejection },
+    #[error("no usable RPC URL left")]
+    NoRpcUrl,
+    #[error("dropped block explorer URL {url:?}: {reason}")]
+    DroppedExplorerUrl { url: String, reason: UrlRejection },
+    #[error("resolved icon {name:?} to {url:?}")]
+    ResolvedIcon { name: String, url: String },
+    #[error("dropped icon {icon:?}: not an https URL and could not be resolved")]
+    DroppedIcon { icon: String },
+    #[error("native currency symbol {0:?} must be 2 to 6 characters")]
+    InvalidSymbol(String),
+    #[error("native currency decimals must be 18, got {0}")]
+    InvalidDecimals(u8),
+}
+
+impl Eip3085Issue {
+    /// Whether the issue could not be fixed, so the wallet would reject the request.
+    pub fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            Self::InvalidChainId(_)
+                | Self::ZeroChainId
+                | Self::ChainIdTooLarge(_)
+                | Self::EmptyChainName
+                | Self::NoRpcUrl
+                | Self::InvalidSymbol(_)
+                | Self::InvalidDecimals(_)
+        )
+    }
+}
+
+/// Parameters a wallet would still reject after sanitizing.
+#[derive(Clone, Debug, Error)]
+#[error("wallet would reject addEthereumChain params: {}", describe(.issues))]
+pub struct RejectedParams {
+    /// Every issue found, fatal or not.
+    pub issues: Vec<Eip3085Issue>,
+}
+
+fn describe(issues: &[Eip3085Issue]) -> String {
+    issues
+        .iter()
+        .filter(|issue| issue.is_fatal())
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Outcome of [`Eip3085Params::sanitize`].
+#[derive(Clone, Debug)]
+pub struct Sanitized {
+    /// Sanitized parameters.
+    pub params: Eip3085Params,
+    /// Everything dropped, fixed or left unfixable, in field order.
+    pub issues: Vec<Eip3085Issue>,
+}
+
+impl Sanitized {
+    /// Whether a wallet would accept [`Sanitized::params`].
+    pub fn is_acceptable(&self) -> bool {
+        !self.issues.iter().any(Eip3085Issue::is_fatal)
+    }
+
+    /// The sanitized parameters, or every issue if some could not be fixed.
+    pub fn into_result(self) -> Result<Eip3085Params, RejectedParams> {
+        if self.is_acceptable() {
+            Ok(self.params)
+        } else {
+            Err(RejectedParams {
+                issues: self.issues,
+            })
+        }
+    }
+}
+
+impl Eip3085Params {
+    /// Sanitizes for wallets, dropping icon names that aren't URLs.
+    pub fn sanitize(&self) -> Sanitized {
+        self.sanitize_with(|_| None)
+    }
+
+    /// Sanitizes for wallets:
+    /// - the chain ID must be a `0x` hex number from 1 to [`MAX_SAFE_CHAIN_ID`],
+    ///   and is normalized to lowercase without leading zeros;
+    /// - the chain name is trimmed and must not be empty;
+    /// - only unique `https://` RPC URLs without templates are kept, and at
+    ///   least one must remain;
+    /// - only unique `https://` block explorer URLs are kept;
+    /// - icons that aren't `https://` URLs (e.g. chains.json icon names such
+    ///   as "ethereum") go through `resolve_icon` and are dropped if it
+    ///   returns `None` or a non-https URL;
+    /// - the currency symbol must have 2 to 6 characters and decimals must be 18.
+    pub fn sanitize_with(&self, resolve_icon: impl Fn(&str) -> Option<String>) -> Sanitized {
+        let mut issues = Vec::new();
+        let mut params = self.clone();
+
+        match parse_hex_chain_id(&self.chain_id) {
+            None => issues.push(Eip3085Issue::InvalidChainId(self.chain_id.clone())),
+            Some(0) => issues.push(Eip3085Issue::ZeroChainId),
+            Some(id) if id > MAX_SAFE_CHAIN_ID => issues.push(Eip3085Issue::ChainIdTooLarge(id)),
+            Some(id) => {
+                let canonical = format!("0x{id:x}");
+                if canonical != self.chain_id {
+                    issues.push(Eip3085Issue::NormalizedChainId {
+                        from: self.chain_id.clone(),
+                        to: canonical.clone(),
+                    });
+                    params.chain_id = canonical;
+                }
+            }
+        }
+
+        let name = self.chain_name.trim();
+        if name.is_empty() {
+            issues.push(Eip3085Issue::EmptyChainName);
+        } else if name != self.chain_name {
+            issues.push(Eip3085Issue::TrimmedChainName(self.chain_name.clone()));
+            params.chain_name = name.to_string();
+        }
+
+        params.rpc_urls = keep_urls(&self.rpc_urls, |url, reason| {
+            issues.push(Eip3085Issue::DroppedRpcUrl { url, reason })
+        });
+        if params.rpc_urls.is_empty() {
+            issues.push(Eip3085Issue::NoRpcUrl);
+        }
+
+        params.block_explorer_urls = keep_urls(&self.block_explorer_urls, |url, reason| {
+            issues.push(Eip3085Issue::DroppedExplorerUrl { url, reason })
+        });
+
+        params.icon_urls = Vec::new();
+        for icon in &self.icon_urls {
+            if check_url(icon).is_ok() {
+                params.icon_urls.push(icon.clone());
+                continue;
+            }
+            match resolve_icon(icon).filter(|url| check_url(url).is_ok()) {
+                Some(url) => {
+                    issues.push(Eip3085Issue::ResolvedIcon {
+                        name: icon.clone(),
+                        url: url.clone(),
+                    });
+                    params.icon_urls.push(url);
+                }
+                None => issues.push(Eip3085Issue::DroppedIcon { icon: icon.clone() }),
+            }
+        }
+
+        let symbol = &self.native_currency.symbol;
+        if !SYMBOL_LENGTH.contains(&symbol.chars().count()) {
+            issues.push(Eip3085Issue::InvalidSymbol(symbol.clone()));
+        }
+        if self.native_currency.decimals != NATIVE_CURRENCY_DECIMALS {
+            issues.push(Eip3085Issue::InvalidDecimals(self.native_currency.decimals));
+        }
+
+        Sanitized { params, issues }
+    }
+}
+
+/// Parses a `0x`-prefixed hex chain ID (any case, leading zeros allowed).
+pub(crate) fn parse_hex_chain_id(chain_id: &str) -> Option<u64> {
+    let digits = chain_id
+        .strip_prefix("0x")
+        .or_else(|| chain_id.strip_prefix("0X"))?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u64::from_str_radix(digits, 16).ok()
+}
+
+/// Checks that `url` is a template-free `https://` URL.
+fn check_url(url: &str) -> Result<(), UrlRejection> {
+    let Some(rest) = url
+        .get(..8)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("https://"))
+        .map(|_| &url[8..])
+    else {
+        return Err(UrlRejection::NotHttps);
+    };
+    if url.contains(['{', '}']) {
+        return Err(UrlRejection::Template);
+    }
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if host.is_empty() || url.contains(char::is_whitespace) {
+        return Err(UrlRejection::Malformed);
+    }
+    Ok(())
+}
+
+fn keep_urls(urls: &[String], mut dropped: impl FnMut(String, UrlRejection)) -> Vec<String> {
+    let mut kept: Vec<String> = Vec::new();
+    for url in urls {
+        let result = check_url(url).and_then(|()| {
+            if kept.contains(url) {
+                Err(UrlRejection::Duplicate)
+            } else {
+                Ok(())
+            }
+        });
+        match result {
+            Ok(()) => kept.push(url.clone()),
+            Err(reason) => dropped(url.clone(), reason),
+        }
+    }
+    kept
+}
+
+/// How risky it is to accept a `wallet_addEthereumChain` request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RiskLevel {
+    /// Matches the registry.
+    Low,
+    /// Can't be fully verified or differs cosmetically; ask the user to double-check.
+    Medium,
+    /// Likely phishing or broken; block or warn prominently.
+    High,
+}
+
+impl RiskLevel {
+    /// Lowercase label for display, e.g. "high".
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+}
+
+impl fmt::Display for RiskLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Difference between an incoming request and the registry.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum Eip3085Finding {
+    #[error("chain id {0:?} is not a 0x-prefixed hex number")]
+    InvalidChainId(String),
+    #[error("chain id {0} is not in the registry")]
+    UnknownChain(u64),
+    #[error("chain is red-flagged: {}", flags.iter().map(RedFlag::as_str).collect::<Vec<_>>().join(", "))]
+    RedFlagged { flags: Vec<RedFlag> },
+    #[error("chain is deprecated")]
+    Deprecated,
+    #[error("chain name {got:?} does not match {expected:?}")]
+    NameMismatch { expected: String, got: String },
+    #[error("currency symbol {got:?} does not match {expected:?}")]
+    SymbolMismatch { expected: String, got: String },
+    #[error("currency decimals {got} do not match {expected}")]
+    DecimalsMismatch { expected: u8, got: u8 },
+    #[error("no RPC URL to check")]
+    NoRpcUrl,
+    #[error("RPC endpoint on {0:?} is not listed for this chain")]
+    UnknownRpcHost(String),
+    #[error("RPC URL {0:?} is not https")]
+    NonHttpsRpcUrl(String),
+    #[error("block explorer on {0:?} is not listed for this chain")]
+    UnknownExplorerHost(String),
+    #[error("block explorer URL {0:?} is not https")]
+    NonHttpsExplorerUrl(String),
+}
+
+impl Eip3085Finding {
+    /// Risk contributed by this finding.
+    pub fn risk(&self) -> RiskLevel {
+        match self {
+            Self::InvalidChainId(_)
+            | Self::RedFlagged { .. }
+            | Self::SymbolMismatch { .. }
+            | Self::DecimalsMismatch { .. }
+            | Self::UnknownRpcHost(_)
+            | Self::NonHttpsRpcUrl(_) => RiskLevel::High,
+            Self::UnknownChain(_)
+            | Self::Deprecated
+            | Self::NameMismatch { .. }
+            | Self::NoRpcUrl
+            | Self::UnknownExplorerHost(_)
+            | Self::NonHttpsExplorerUrl(_) => RiskLevel::Medium,
+        }
+    }
+}
+
+/// Result of [`Eip3085Params::verify`].
+#[derive(Clone, Debug)]
+pub struct Eip3085Verdict {
+    /// Requested chain ID, if it parsed.
+    pub chain_id: Option<u64>,
+    /// Registry record the request was compared with.
+    pub record: Option<&'static ChainRecord>,
+    /// Every difference found.
+    pub findings: Vec<Eip3085Finding>,
+}
+
+impl Eip3085Verdict {
+    /// Highest risk among the findings; [`RiskLevel::Low`] when there are none.
+    pub fn risk(&self) -> RiskLevel {
+        self.findings
+            .iter()
+            .map(Eip3085Finding::risk)
+            .max()
+            .unwrap_or(RiskLevel::Low)
+    }
+
+    /// Whether the request matches the registry exactly.
+    pub fn is_verified(&self) -> bool {
+        self.record.is_some() && self.findings.is_empty()
+    }
+}
+
+/// Words dapps commonly add or leave out of chain names.
+const GENERIC_NAME_WORDS: &[&str] = &["mainnet", "main", "network", "chain"];
+
+/// Normalized chain name without generic words, so that "Ethereum" matches
+/// "Ethereum Mainnet".
+fn canonical_name(name: &str) -> String {
+    let normalized = normalize(name);
+    let stripped = normalized
+        .split(' ')
+        .filter(|word| !GENERIC_NAME_WORDS.contains(word))
+        .collect::<Vec<_>>()
+        .join(" ");
+    if stripped.is_empty() {
+        normalized
+    } else {
+        stripped
+    }
+}
+
+impl Eip3085Params {
+    /// Checks an untrusted `wallet_addEthereumChain` request against the
+    /// bundled registry.
+    ///
+    /// Reports a red-flagged or deprecated chain, a chain name matching
+    /// neither the registry's name, title nor shortName (ignoring case,
+    /// punctuation and words like "Mainnet"), a different currency
+    /// symbol or decimals, RPC or explorer URLs the registry doesn't list
+    /// for the chain (matched on host and base path, so API keys after it are
+    /// ignored) or that aren't `https://`, and a request without RPC URLs.
+    /// Chains missing from the registry can't be verified and are reported as
+    /// [`Eip3085Finding::UnknownChain`].
+    pub fn verify(&self) -> Eip3085Verdict {
+        let mut findings = Vec::new();
+        let Some(chain_id) = parse_hex_chain_id(&self.chain_id) else {
+            findings.push(Eip3085Finding::InvalidChainId(self.chain_id.clone()));
+            return Eip3085Verdict {
+                chain_id: None,
+                record: None,
+                findings,
+            };
+        };
+        let Some(record) = find_record(chain_id) else {
+            findings.push(Eip3085Finding::UnknownChain(chain_id));
+            return Eip3085Verdict {
+                chain_id: Some(chain_id),
+                record: None,
+                findings,
+            };
+        };
+
+        let flags = record.red_flag_kinds();
+        if !flags.is_empty() {
+            findings.push(Eip3085Finding::RedFlagged { flags });
+        }
+        if record.status() == ChainStatus::Deprecated {
+            findings.push(Eip3085Finding::Deprecated);
+        }
+
+        let name = canonical_name(&self.chain_name);
+        let known_names = [
+            Some(&record.name),
+            record.title.as_ref(),
+            Some(&record.short_name),
+        ];
+        if !known_names
+            .into_iter()
+            .flatten()
+            .any(|known| canonical_name(known) == name)
+        {
+            findings.push(Eip3085Finding::NameMismatch {
+                expected: record.name.clone(),
+                got: self.chain_name.clone(),
+            });
+        }
+
+        let expected = &record.native_currency;
+        let got = &self.native_currency;
+        if !got.symbol.trim().eq_ignore_ascii_case(&expected.symbol) {
+            findings.push(Eip3085Finding::SymbolMismatch {
+                expected: expected.symbol.clone(),
+                got: got.symbol.clone(),
+            });
+        }
+        if got.decimals != expected.decimals {
+            findings.push(Eip3085Finding::DecimalsMismatch {
+                expected: expected.decimals,
+                got: got.decimals,
+            });
+        }
+
+        // Shared hosts (rpc.ankr.com, subnets.avax.network, ...) serve many
+        // chains, so the base path has to match too
+        let listed = |kind, url: &str| {
+            listed_by(kind, url)
+                .iter()
+                .any(|chain| chain.id() == chain_id)
+        };
+        // Nothing to compare means nothing was verified
+        if self.rpc_urls.is_empty() {
+            findings.push(Eip3085Finding::NoRpcUrl);
+        }
+        for url in &self.rpc_urls {
+            // Listed endpoints are matched without their scheme
+            if check_url(url) == Err(UrlRejection::NotHttps) {
+                findings.push(Eip3085Finding::NonHttpsRpcUrl(url.clone()));
+            }
+            if !listed(UrlKind::Rpc, url) {
+                findings.push(Eip3085Finding::UnknownRpcHost(
+                    url_host(url).unwrap_or_else(|| url.clone()),
+                ));
+            }
+        }
+        for url in &self.block_explorer_urls {
+            if check_url(url) == Err(UrlRejection::NotHttps) {
+                findings.push(Eip3085Finding::NonHttpsExplorerUrl(url.clone()));
+            }
+            if !listed(UrlKind::Explorer, url) {
+                findings.push(Eip3085Finding::UnknownExplorerHost(
+                    url_host(url).unwrap_or_else(|| url.clone()),
+                ));
+            }
+        }
+
+        Eip3085Verdict {
+            chain_id: Some(chain_id),
+            record: Some(record),
+            findings,
+        }
+    }
+}